@@ -0,0 +1,52 @@
+use aoc::solution::{Error, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    // Each elf's total calories, largest first.
+    type Parsed = Vec<i32>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, Error> {
+        let mut current = 0;
+        let mut calories = vec![];
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                calories.push(current);
+                current = 0;
+            } else {
+                current += line.parse::<i32>().map_err(|e| Error::from(e).at_line(i + 1))?;
+            }
+        }
+        calories.push(current);
+        calories.sort_by(|a, b| b.cmp(a));
+        Ok(calories)
+    }
+
+    fn part1(calories: &Vec<i32>) -> i32 {
+        calories[0]
+    }
+
+    fn part2(calories: &Vec<i32>) -> i32 {
+        calories.iter().take(3).sum::<i32>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    #[test]
+    fn example() {
+        let calories = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&calories), 24000);
+        assert_eq!(Day1::part2(&calories), 45000);
+    }
+
+    #[test]
+    fn rejects_non_numbers() {
+        assert_eq!(Day1::parse("100\nabc").unwrap_err().line(), Some(2));
+    }
+}
@@ -0,0 +1,126 @@
+use aoc::parsing::lines;
+use aoc::solution::{Error, Solution};
+
+pub enum Cmd {
+  Noop,
+  Addx(i64),
+}
+
+struct Cpu {
+  x: i64,
+  cycle: usize,
+  signal_strength: i64,
+  screen: [char; 240],
+}
+
+impl Cpu {
+  fn new() -> Self {
+    Self {
+      x: 1,
+      cycle: 0,
+      signal_strength: 0,
+      screen: ['.'; 240],
+    }
+  }
+
+  fn addx(&mut self, v: i64) {
+    self.x += v;
+  }
+
+  fn one_cycle(&mut self) {
+    self.draw();
+    self.cycle += 1;
+    self.add_signal_strength();
+  }
+
+  fn draw(&mut self) {
+    let diff = i64::abs(self.x - (self.cycle % 40) as i64);
+    if diff == 0 || diff == 1 {
+      self.screen[self.cycle] = '#';
+    }
+  }
+
+  fn add_signal_strength(&mut self) {
+    match self.cycle {
+      20 | 60 | 100 | 140 | 180 | 220 => {
+        self.signal_strength += self.x * self.cycle as i64;
+      },
+      _ => {},
+    }
+  }
+
+  fn fetch_cmd(&mut self, cmd: &Cmd) {
+    match cmd {
+      Cmd::Noop => {
+        self.one_cycle();
+      },
+      Cmd::Addx(v) => {
+        self.one_cycle();
+        self.one_cycle();
+        self.addx(*v);
+      }
+    }
+  }
+}
+
+fn parse_line(line: &str) -> Result<Cmd, Error> {
+  let cmd = line.split(" ").collect::<Vec<&str>>();
+  match cmd[..] {
+    ["noop"] => Ok(Cmd::Noop),
+    ["addx", v] => Ok(Cmd::Addx(v.parse::<i64>()?)),
+    _ => Err(Error::new("Command is not supported!")),
+  }
+}
+
+// Runs the whole program. The screen only has room for 240 cycles.
+fn execute(cmds: &[Cmd]) -> Cpu {
+  let mut cpu = Cpu::new();
+  for cmd in cmds {
+    cpu.fetch_cmd(cmd);
+  }
+  cpu
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+  type Parsed = Vec<Cmd>;
+  type Answer = String;
+
+  fn parse(input: &str) -> Result<Vec<Cmd>, Error> {
+    let cmds = lines(input, parse_line)?;
+    let cycles: usize = cmds.iter().map(|cmd| match cmd { Cmd::Noop => 1, Cmd::Addx(_) => 2 }).sum();
+    if cycles > 240 {
+      return Err(Error::new(format!("Program runs for {} cycles, more than the screen's 240", cycles)));
+    }
+    Ok(cmds)
+  }
+
+  fn part1(cmds: &Vec<Cmd>) -> String {
+    execute(cmds).signal_strength.to_string()
+  }
+
+  fn part2(cmds: &Vec<Cmd>) -> String {
+    let cpu = execute(cmds);
+    let rows: Vec<String> = cpu.screen.chunks(40).map(|row| row.iter().collect()).collect();
+    rows.join("\n")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constant_register() {
+    let cmds = Day10::parse(&vec!["noop"; 240].join("\n")).unwrap();
+    assert_eq!(Day10::part1(&cmds), (20 + 60 + 100 + 140 + 180 + 220).to_string());
+    let row = format!("###{}", ".".repeat(37));
+    assert_eq!(Day10::part2(&cmds), vec![row; 6].join("\n"));
+  }
+
+  #[test]
+  fn rejects_unknown_command() {
+    assert_eq!(Day10::parse("noop\naddx\nnoop").err().unwrap().line(), Some(2));
+  }
+}
@@ -0,0 +1,299 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc::integer::Integer;
+use aoc::modint::{DynModInt, Modulus};
+use aoc::parsing::numbers;
+use aoc::solution::{Error, Solution};
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add,
+    Multi,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey<T> {
+    id: usize,
+    items: Vec<T>,
+    operation: Operation,
+    added: T,
+    divisible: T,
+    is_old: bool,
+    throw_true: usize,
+    throw_false: usize,
+}
+
+// The number a line has to carry, such as a monkey id or a divisor.
+fn expect_number<T: FromStr>(line: &str) -> Result<T, Error>
+where
+    T::Err: Display,
+{
+    let number = numbers(line)?.into_iter().next();
+    number.ok_or_else(|| Error::new(format!("Expected a number in {:?}", line)))
+}
+
+// Adds one line of the notes to `monkeys`, the last of which is the monkey
+// being described.
+fn parse_line<T: Integer>(line: &str, monkeys: &mut Vec<Monkey<T>>) -> Result<(), Error>
+where
+    T::Err: Display,
+{
+    let line = line.trim();
+    let items: Vec<T> = numbers(line)?;
+
+    if line.is_empty() {
+        return Ok(());
+    } else if line.starts_with("Monkey") {
+        let id = expect_number::<usize>(line)?;
+        if id != monkeys.len() {
+            return Err(Error::new("Monkeys must be numbered in order from 0"));
+        }
+        monkeys.push(Monkey {
+            id,
+            items: vec![],
+            operation: Operation::Add,
+            added: T::zero(),
+            divisible: T::zero(),
+            is_old: false,
+            throw_true: 0,
+            throw_false: 0,
+        });
+        return Ok(());
+    }
+
+    let Some(monkey) = monkeys.last_mut() else {
+        return Err(Error::new("Expected a monkey header"));
+    };
+    if line.starts_with("Starting") {
+        monkey.items = items;
+    } else if line.starts_with("Operation") {
+        if line.contains("*") {
+            monkey.operation = Operation::Multi;
+        }
+        if !items.is_empty() {
+            monkey.added = items[0].clone();
+        } else {
+            monkey.is_old = true;
+        }
+    } else if line.starts_with("Test") {
+        monkey.divisible = expect_number(line)?;
+    } else if line.starts_with("If true") {
+        monkey.throw_true = expect_number(line)?;
+    } else if line.starts_with("If false") {
+        monkey.throw_false = expect_number(line)?;
+    } else {
+        return Err(Error::new(format!("Unrecognized line {:?}", line)));
+    }
+    Ok(())
+}
+
+fn parse_monkeys<T: Integer>(input: &str) -> Result<Vec<Monkey<T>>, Error>
+where
+    T::Err: Display,
+{
+    let mut monkeys: Vec<Monkey<T>> = vec![];
+    for (i, line) in input.lines().enumerate() {
+        parse_line(line, &mut monkeys).map_err(|e| e.at_line(i + 1))?;
+    }
+
+    if monkeys.len() < 2 {
+        return Err(Error::new("Monkey business needs at least two monkeys"));
+    }
+    let targets_exist = monkeys.iter()
+        .all(|monkey| monkey.throw_true < monkeys.len() && monkey.throw_false < monkeys.len());
+    if !targets_exist {
+        return Err(Error::new("Monkeys can only throw to other listed monkeys"));
+    }
+    if monkeys.iter().any(|monkey| monkey.divisible.is_zero()) {
+        return Err(Error::new("Every monkey needs a nonzero test divisor"));
+    }
+    Ok(monkeys)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey<u64>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let monkeys = parse_monkeys(input)?;
+        // Part 1 keeps exact worry levels, so play it once here to make sure
+        // they fit in a u64.
+        if solve(20, &monkeys, true).is_none() {
+            return Err(Error::new("Worry levels overflow a u64 within 20 rounds"));
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Parsed) -> usize {
+        solve(20, monkeys, true).expect("parse checks that part 1 fits in a u64")
+    }
+
+    fn part2(monkeys: &Self::Parsed) -> usize {
+        solve_modular(10000, monkeys)
+    }
+}
+
+// Plays the rounds and returns the monkey business. `inspect` gives an item's
+// new worry level and whether it passes the monkey's test, or `None` to stop
+// the game when the level cannot be computed.
+fn play<T, W>(
+    rounds: usize,
+    monkeys: &[Monkey<T>],
+    mut items: Vec<Vec<W>>,
+    inspect: impl Fn(W, &Monkey<T>) -> Option<(W, bool)>,
+) -> Option<usize> {
+    let mut inspects = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            inspects[monkey.id] += items[i].len();
+            for item in std::mem::take(&mut items[i]) {
+                let (worry_level, passes) = inspect(item, monkey)?;
+                let throw_index = if passes { monkey.throw_true } else { monkey.throw_false };
+                items[throw_index].push(worry_level);
+            }
+        }
+    }
+
+    inspects.sort_by(|a, b| b.cmp(a));
+    Some(inspects[0] * inspects[1])
+}
+
+// Exact worry levels, or `None` once one overflows `T`.
+fn solve<T: Integer>(rounds: usize, monkeys: &[Monkey<T>], part_1: bool) -> Option<usize> {
+    let relief = if part_1 { T::one() + T::one() + T::one() } else { T::one() };
+    let items = monkeys.iter().map(|monkey| monkey.items.clone()).collect();
+
+    play(rounds, monkeys, items, |item, monkey| {
+        let worry_level = new_worry_level(item, monkey)? / relief.clone();
+        let passes = (worry_level.clone() % monkey.divisible.clone()).is_zero();
+        Some((worry_level, passes))
+    })
+}
+
+// Worry levels modulo the LCM of the divisors, which keeps every test intact.
+fn solve_modular(rounds: usize, monkeys: &[Monkey<u64>]) -> usize {
+    let lcm_of_divisors = monkeys.iter()
+        .map(|monkey| monkey.divisible)
+        .fold(1, |acc, x| acc.lcm(&x));
+    let modulus = Modulus::new(lcm_of_divisors);
+
+    let items = monkeys.iter()
+        .map(|monkey| monkey.items.iter().map(|&item| modulus.of(item)).collect())
+        .collect();
+
+    play(rounds, monkeys, items, |item: DynModInt, monkey| {
+        let added = if monkey.is_old { item } else { modulus.of(monkey.added) };
+        let worry_level = match monkey.operation {
+            Operation::Add => item + added,
+            Operation::Multi => item * added,
+        };
+        Some((worry_level, worry_level.value() % monkey.divisible == 0))
+    })
+    .expect("reduced worry levels cannot overflow")
+}
+
+fn new_worry_level<T: Integer>(worry_level: T, monkey: &Monkey<T>) -> Option<T> {
+    let added = if monkey.is_old { worry_level.clone() } else { monkey.added.clone() };
+    match monkey.operation {
+        Operation::Add => worry_level.checked_add(&added),
+        Operation::Multi => worry_level.checked_mul(&added),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::bigint::BigInt;
+
+    // Rounds of part 2 checked against exact BigInt arithmetic. Without the
+    // reduction worry levels grow exponentially, so only a few are feasible.
+    const ORACLE_ROUNDS: usize = 10;
+
+    const EXAMPLE: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn example() {
+        let monkeys = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&monkeys), 10605);
+        assert_eq!(Day11::part2(&monkeys), 2713310158);
+    }
+
+    #[test]
+    fn modular_matches_exact() {
+        let monkeys = Day11::parse(EXAMPLE).unwrap();
+        let exact = solve(ORACLE_ROUNDS, &parse_monkeys::<BigInt>(EXAMPLE).unwrap(), false);
+        assert_eq!(Some(solve_modular(ORACLE_ROUNDS, &monkeys)), exact);
+    }
+
+    #[test]
+    fn rejects_oversized_item() {
+        let err = Day11::parse(&EXAMPLE.replace("79, 98", "79, 99999999999999999999999"));
+        assert_eq!(err.err().unwrap().line(), Some(2));
+    }
+
+    #[test]
+    fn rejects_missing_numbers() {
+        let cases = [
+            ("Monkey 0:", "Monkey :", 1),
+            ("divisible by 23", "divisible by", 4),
+            ("If true: throw to monkey 2", "If true: throw to monkey", 5),
+            ("If false: throw to monkey 3", "If false: throw to monkey", 6),
+        ];
+        for (from, to, line) in cases {
+            let err = Day11::parse(&EXAMPLE.replacen(from, to, 1)).err().unwrap();
+            assert_eq!(err.line(), Some(line), "{}", to);
+        }
+    }
+
+    #[test]
+    fn rejects_missing_target() {
+        let err = Day11::parse(&EXAMPLE.replace("throw to monkey 3", "throw to monkey 4"));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn rejects_fewer_than_two_monkeys() {
+        let single = EXAMPLE.split("\n\n").next().unwrap().replace("monkey 2", "monkey 0");
+        let single = single.replace("monkey 3", "monkey 0");
+        for input in [single.as_str(), ""] {
+            let err = Day11::parse(input).err().unwrap();
+            assert_eq!(err.message(), "Monkey business needs at least two monkeys");
+        }
+    }
+
+    #[test]
+    fn rejects_worry_levels_that_overflow() {
+        // Monkey 2 squares 2^32 in the first round.
+        let err = Day11::parse(&EXAMPLE.replace("79, 60, 97", "79, 60, 4294967296")).err().unwrap();
+        assert_eq!(err.message(), "Worry levels overflow a u64 within 20 rounds");
+    }
+}
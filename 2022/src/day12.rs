@@ -0,0 +1,150 @@
+use std::collections::BinaryHeap;
+use std::cmp::Reverse;
+
+use aoc::solution::{Error, Solution};
+
+pub struct Heightmap {
+    grid: Vec<Vec<u8>>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+// Fewest steps from S to E, if E can be reached at all.
+fn climb(map: &Heightmap) -> Option<usize> {
+    let grid = &map.grid;
+    let (start, end) = (map.start, map.end);
+
+    let mut visited = vec![vec![false; grid[0].len()]; grid.len()];
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((0, start))); // (steps, position)
+
+    visited[start.0][start.1] = true;
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+    while let Some(Reverse((steps, current))) = queue.pop() {
+        if current == end {
+            return Some(steps);
+        }
+
+        for &(dx, dy) in &directions {
+            let new_x = current.0 as isize + dx;
+            let new_y = current.1 as isize + dy;
+
+            if new_x >= 0 && new_x < grid.len() as isize && new_y >= 0 && new_y < grid[0].len() as isize {
+                let new_pos = (new_x as usize, new_y as usize);
+                if !visited[new_pos.0][new_pos.1] && can_move(grid[current.0][current.1], grid[new_pos.0][new_pos.1]) {
+                    visited[new_pos.0][new_pos.1] = true;
+                    queue.push(Reverse((steps + 1, new_pos)));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn can_move(from: u8, to: u8) -> bool {
+    from >= to - 1
+}
+
+fn part2(map: &Heightmap) -> usize {
+    let grid = &map.grid;
+    let end = map.end;
+
+    let mut visited = vec![vec![false; grid[0].len()]; grid.len()];
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((0, end))); // (steps, position)
+
+    visited[end.0][end.1] = true;
+    let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+    while let Some(Reverse((steps, current))) = queue.pop() {
+        if grid[current.0][current.1] == b'a' {
+            return steps;
+        }
+
+        for &(dx, dy) in &directions {
+            let new_x = current.0 as isize + dx;
+            let new_y = current.1 as isize + dy;
+
+            if new_x >= 0 && new_x < grid.len() as isize && new_y >= 0 && new_y < grid[0].len() as isize {
+                let new_pos = (new_x as usize, new_y as usize);
+                if !visited[new_pos.0][new_pos.1] && can_move(grid[new_pos.0][new_pos.1], grid[current.0][current.1]) {
+                    visited[new_pos.0][new_pos.1] = true;
+                    queue.push(Reverse((steps + 1, new_pos)));
+                }
+            }
+        }
+    }
+    unreachable!("S is a lowest square and parse checked that it reaches E");
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Heightmap;
+    type Answer = usize;
+
+    // Replaces S and E with the heights they stand for.
+    fn parse(input: &str) -> Result<Heightmap, Error> {
+        let mut grid: Vec<Vec<u8>> = input
+            .lines()
+            .map(|line| line.bytes().collect())
+            .collect();
+
+        let mut start = None;
+        let mut end = None;
+
+        for (i, row) in grid.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                if *cell == b'S' {
+                    start = Some((i, j));
+                    *cell = b'a';
+                } else if *cell == b'E' {
+                    end = Some((i, j));
+                    *cell = b'z';
+                } else if !cell.is_ascii_lowercase() {
+                    let message = format!("Unexpected square {:?}", *cell as char);
+                    return Err(Error::new(message).at_line(i + 1));
+                }
+            }
+        }
+        if grid.iter().any(|row| row.len() != grid[0].len()) {
+            return Err(Error::new("Every row of the map must have the same length"));
+        }
+
+        let (Some(start), Some(end)) = (start, end) else {
+            return Err(Error::new("The map needs both a start S and an end E"));
+        };
+        let map = Heightmap { grid, start, end };
+        if climb(&map).is_none() {
+            return Err(Error::new("The end E cannot be reached from the start S"));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Heightmap) -> usize {
+        climb(map).unwrap()
+    }
+
+    fn part2(map: &Heightmap) -> usize {
+        part2(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let map = Day12::parse("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+        assert_eq!(Day12::part1(&map), 31);
+        assert_eq!(Day12::part2(&map), 29);
+    }
+
+    #[test]
+    fn rejects_unreachable_end() {
+        assert!(Day12::parse("Sa\nzE").is_err());
+        assert_eq!(Day12::part1(&Day12::parse("SbcdefghijklmnopqrstuvwxyE").unwrap()), 25);
+    }
+}
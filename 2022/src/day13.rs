@@ -0,0 +1,267 @@
+use std::cmp::Ordering;
+
+use aoc::parsing::numbers;
+use aoc::solution::{Error, Solution};
+
+#[derive(Debug)]
+pub struct Node {
+    value: i32,
+    is_list: bool,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(value: i32, is_list: bool) -> Self {
+        Node {
+            value,
+            children: vec![],
+            is_list,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Parser {
+    idx: usize,
+}
+
+impl Parser {
+    fn new() -> Self {
+        Parser { idx: 0 }
+    }
+
+    fn parse(&mut self, val: String) -> Node {
+        self.idx = 1;
+        let vals = val.chars().collect::<Vec<char>>();
+        let mut node = Node::new(0, true);
+        self.traverse(&vals, &mut node);
+        node
+    }
+
+    fn traverse(&mut self, vals: &[char], node: &mut Node) {
+        while self.idx < vals.len() {
+            let c = vals[self.idx];
+            self.idx += 1;
+            match c {
+                '[' => {
+                    let mut node_list_child = Node::new(0, true);
+                    self.traverse(vals, &mut node_list_child);
+                    node.children.push(node_list_child);
+                }
+                ']' => {
+                    return;
+                }
+                digit if c.is_ascii_digit() => {
+                    let mut v = digit.to_string();
+                    let mut i = self.idx;
+                    while vals[i].is_ascii_digit() {
+                        v.push(vals[i]);
+                        i += 1;
+                    }
+                    self.idx = i;
+                    let value: i32 = v.parse().unwrap();
+                    node.children.push(Node::new(value, false));
+                }
+                _ => {
+                    continue;
+                }
+            }
+        }
+    }
+}
+
+fn is_ordered(left: &Node, right: &Node) -> Option<bool> {
+    let mut i = 0;
+    while i < left.children.len() || i < right.children.len() {
+        if i >= left.children.len() {
+            return Some(true);
+        } else if i >= right.children.len() {
+            return Some(false);
+        }
+
+        let left_node = &left.children[i];
+        let right_node = &right.children[i];
+        
+        if !left_node.is_list && !right_node.is_list {
+            if left_node.value < right_node.value {
+                return Some(true);
+            } else if left_node.value > right_node.value {
+                return Some(false);
+            }
+        } else if left_node.is_list && right_node.is_list {
+            if let Some(ordered) = is_ordered(left_node, right_node) {
+                return Some(ordered);
+            }
+        } else if left_node.is_list ^ right_node.is_list {
+            let mut temp_node = Node::new(0, true);
+            temp_node.children.push(Node::new(if !left_node.is_list { left_node.value } else {
+                right_node.value
+            }, false));
+
+            if !left_node.is_list {
+                let ordered = is_ordered(&temp_node, right_node);
+                if ordered.is_some() {
+                    return ordered
+                }
+            } else {
+                let ordered = is_ordered(left_node, &temp_node);
+                if ordered.is_some() {
+                    return ordered
+                }
+            }
+        }
+
+        i += 1;
+    }
+
+    None
+}
+
+fn solve_part1(packets: &[Node]) -> i32 {
+    let mut ans: i32 = 0;
+
+    for (i, pair) in packets.chunks(2).enumerate() {
+        if let Some(ordered) = is_ordered(&pair[0], &pair[1]) {
+            if ordered {
+                ans += i as i32 + 1;
+            }
+        }
+    }
+    
+    ans
+}
+
+fn custom_cmp(left: &Node, right: &Node) -> Ordering {
+    if let Some(ordered) = is_ordered(left, right) {
+        if ordered {
+            return Ordering::Less
+        } else {
+            return Ordering::Greater
+        }
+    }
+    Ordering::Equal
+}
+
+fn solve_part2(packets: &[Node]) -> i32 {
+    let mut parser = Parser::new();
+    let mut nodes: Vec<&Node> = packets.iter().collect();
+    let dividers = [parser.parse("[[2]]".to_string()), parser.parse("[[6]]".to_string())];
+    nodes.extend(&dividers);
+
+    nodes.sort_by(|a, b| custom_cmp(a, b));
+
+    let mut idx1 = 0;
+    let mut idx2 = 0;
+    for (i, node) in nodes.iter().enumerate() {
+        if node.children.len() == 1 {
+            let only_child = &node.children[0];
+            if only_child.is_list && only_child.children.len() == 1 {
+                if only_child.children[0].value == 2 {
+                    idx1 = i as i32 + 1;
+                } else if only_child.children[0].value == 6 {
+                    idx2 = i as i32 + 1;
+                }
+            }
+        }
+    }
+
+    idx1 * idx2
+ }
+
+// Packets are lists of lists and integers, so they only ever contain
+// brackets, commas and digits. The integers must also fit the parser's i32.
+fn check_packet(line: &str) -> Result<(), Error> {
+    let mut depth = 0;
+    for (i, c) in line.chars().enumerate() {
+        if i > 0 && depth == 0 {
+            return Err(Error::new(format!("Packet must be a single list, got {:?}", line)));
+        }
+        match c {
+            '[' => depth += 1,
+            ']' if depth > 0 => depth -= 1,
+            ',' | '0'..='9' if depth > 0 => {}
+            _ => return Err(Error::new(format!("Unexpected {:?} in packet {:?}", c, line))),
+        }
+    }
+    if depth != 0 || line.is_empty() {
+        return Err(Error::new(format!("Unbalanced brackets in {:?}", line)));
+    }
+    numbers::<i32>(line)?;
+    Ok(())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    // Every packet in order, so pairs are consecutive.
+    type Parsed = Vec<Node>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<Node>, Error> {
+        let mut parser = Parser::new();
+        let mut packets = vec![];
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            check_packet(line).map_err(|e| e.at_line(i + 1))?;
+            packets.push(parser.parse(line.to_string()));
+        }
+        if packets.len() % 2 != 0 {
+            return Err(Error::new("Packets must come in pairs"));
+        }
+        Ok(packets)
+    }
+
+    fn part1(packets: &Vec<Node>) -> i32 {
+        solve_part1(packets)
+    }
+
+    fn part2(packets: &Vec<Node>) -> i32 {
+        solve_part2(packets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    #[test]
+    fn example() {
+        let packets = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&packets), 13);
+        assert_eq!(Day13::part2(&packets), 140);
+    }
+
+    #[test]
+    fn rejects_malformed_packets() {
+        for packet in ["[1,2", "1,2]", "[1]]", "[a]", "[1][2]", "", "[99999999999]"] {
+            assert!(check_packet(packet).is_err(), "{:?}", packet);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use aoc::parsing::lines;
+use aoc::solution::{Error, Solution};
+
+fn get_move_value(your_move: &str) -> i32 {
+    if your_move == "X" {
+        return 1;
+    } else if your_move == "Y" {
+        return 2;
+    }
+    3
+}
+
+fn you_win(your_move: &str, opponent_move: &str) -> bool {
+    (your_move == "Y" && opponent_move == "A")
+        || (your_move == "X" && opponent_move == "C")
+        || (your_move == "Z" && opponent_move == "B")
+}
+
+fn is_equal(your_move: &str, opponent_move: &str) -> bool {
+    (your_move == "X" && opponent_move == "A")
+        || (your_move == "Y" && opponent_move == "B")
+        || (your_move == "Z" && opponent_move == "C")
+}
+
+fn move_value(opponent_move: &str, should_win: bool) -> i32 {
+    if opponent_move == "A" {
+        return if should_win { 2 } else { 3 };
+    } else if opponent_move == "B" {
+        return if should_win { 3 } else { 1 };
+    }
+    if should_win { 1 } else { 2 }
+}
+
+fn draw_move_value(opponent_move: &str) -> i32 {
+    if opponent_move == "A" {
+        return 1;
+    } else if opponent_move == "B" {
+        return 2;
+    }
+    3
+}
+
+fn parse_round(line: &str) -> Result<(String, String), Error> {
+    match line.split_whitespace().collect::<Vec<&str>>()[..] {
+        [opponent_move @ ("A" | "B" | "C"), your_move @ ("X" | "Y" | "Z")] => {
+            Ok((opponent_move.to_string(), your_move.to_string()))
+        }
+        _ => Err(Error::new(format!("Expected a round like \"A Y\", got {:?}", line))),
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    // (opponent move, your column) for each round.
+    type Parsed = Vec<(String, String)>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        lines(input, parse_round)
+    }
+
+    fn part1(rounds: &Self::Parsed) -> i32 {
+        let mut score = 0;
+        for (opponent_move, your_move) in rounds {
+            score += get_move_value(your_move);
+            if is_equal(your_move, opponent_move) {
+                score += 3;
+            } else if you_win(your_move, opponent_move) {
+                score += 6;
+            }
+        }
+        score
+    }
+
+    fn part2(rounds: &Self::Parsed) -> i32 {
+        let mut score = 0;
+        for (opponent_move, your_move) in rounds {
+            match your_move.as_str() {
+                "Z" => {
+                    score += 6;
+                    score += move_value(opponent_move, true);
+                }
+                "Y" => {
+                    score += 3;
+                    score += draw_move_value(opponent_move);
+                }
+                "X" => {
+                    score += move_value(opponent_move, false);
+                }
+                _ => {}
+            }
+        }
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let rounds = Day2::parse("A Y\nB X\nC Z").unwrap();
+        assert_eq!(Day2::part1(&rounds), 15);
+        assert_eq!(Day2::part2(&rounds), 12);
+    }
+}
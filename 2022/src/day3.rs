@@ -0,0 +1,102 @@
+use aoc::parsing::lines;
+use aoc::solution::{Error, Solution};
+
+fn increment_storage(idx: usize, first: &mut [i32; 52], second: &mut [i32; 52], first_half: bool) {
+    if first_half {
+        first[idx] += 1;
+    } else {
+        second[idx] += 1;
+    }
+}
+
+// Item priority minus one: a-z are 0..26 and A-Z are 26..52.
+fn item_index(c: u8) -> usize {
+    let mut idx = (c - b'A' + 26) as usize;
+    if c.is_ascii_lowercase() {
+        idx = (c - b'a') as usize;
+    }
+    idx
+}
+
+fn parse_rucksack(line: &str) -> Result<String, Error> {
+    if !line.bytes().all(|c| c.is_ascii_alphabetic()) {
+        return Err(Error::new(format!("Items must be ASCII letters, got {:?}", line)));
+    }
+    Ok(line.to_string())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, Error> {
+        lines(input, parse_rucksack)
+    }
+
+    fn part1(rucksacks: &Vec<String>) -> usize {
+        let mut ans = 0;
+
+        for line in rucksacks {
+            let mut first = [0_i32; 52];
+            let mut second = [0_i32; 52];
+            let first_half = line.len() / 2;
+            let mut count = 0;
+
+            for c in line.bytes() {
+                count += 1;
+                increment_storage(item_index(c), &mut first, &mut second, count <= first_half);
+            }
+
+            for i in 0..52 {
+                if first[i] != 0 && second[i] != 0 {
+                    ans += i + 1;
+                }
+            }
+        }
+        ans
+    }
+
+    fn part2(rucksacks: &Vec<String>) -> usize {
+        let mut ans = 0;
+        let mut count = 0;
+        let mut bag = [[0_i32; 52], [0_i32; 52], [0_i32; 52]];
+
+        for line in rucksacks {
+            for c in line.bytes() {
+                bag[count][item_index(c)] += 1;
+            }
+            count += 1;
+            if count == 3 {
+                count = 0;
+                for (i, ((a, b), c)) in bag[0].iter().zip(bag[1]).zip(bag[2]).enumerate() {
+                    if *a != 0 && b != 0 && c != 0 {
+                        ans += i + 1;
+                    }
+                }
+                bag = [[0_i32; 52], [0_i32; 52], [0_i32; 52]];
+            }
+        }
+        ans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn example() {
+        let rucksacks = Day3::parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::part1(&rucksacks), 157);
+        assert_eq!(Day3::part2(&rucksacks), 70);
+    }
+}
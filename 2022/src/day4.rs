@@ -0,0 +1,72 @@
+use aoc::parsing::lines;
+use aoc::solution::{Error, Solution};
+
+pub struct Section {
+    start: i32,
+    end: i32,
+}
+
+fn parse_section(range: &str) -> Result<Section, Error> {
+    if let Some((v1, v2)) = range.split_once('-') {
+        return Ok(Section {
+            start: v1.parse::<i32>()?,
+            end: v2.parse::<i32>()?,
+        });
+    }
+    Err(Error::new(format!("Wrong string format! {}", range)))
+}
+
+fn parse_pair(line: &str) -> Result<(Section, Section), Error> {
+    if let Some((first, second)) = line.split_once(',') {
+        return Ok((parse_section(first)?, parse_section(second)?));
+    }
+    Err(Error::new(format!("Wrong string format! {}", line)))
+}
+
+fn fully_overlap(p1: &Section, p2: &Section) -> bool {
+    (p1.start <= p2.start && p1.end >= p2.end)
+        || (p1.start >= p2.start && p1.end <= p2.end)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<(Section, Section)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        lines(input, parse_pair)
+    }
+
+    fn part1(pairs: &Self::Parsed) -> usize {
+        pairs.iter().filter(|(p1, p2)| fully_overlap(p1, p2)).count()
+    }
+
+    fn part2(pairs: &Self::Parsed) -> usize {
+        pairs.iter()
+            .filter(|(p1, p2)| {
+                (p1.start >= p2.start && p1.start <= p2.end)
+                    || (p1.end >= p2.start && p1.end <= p2.end)
+                    || fully_overlap(p1, p2)
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let pairs = Day4::parse("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8").unwrap();
+        assert_eq!(Day4::part1(&pairs), 2);
+        assert_eq!(Day4::part2(&pairs), 4);
+    }
+
+    #[test]
+    fn rejects_missing_comma() {
+        let err = Day4::parse("2-4,6-8\n2-3 4-5").err().unwrap();
+        assert_eq!(err.line(), Some(2));
+    }
+}
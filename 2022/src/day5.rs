@@ -0,0 +1,149 @@
+use aoc::parsing::numbers;
+use aoc::solution::{Error, Solution};
+
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+pub struct Crates {
+    stacks: Vec<String>,
+    moves: Vec<Move>,
+}
+
+// The drawing above the moves, bottom crate first in each stack. Crate
+// letters sit in every fourth column and the last line numbers the stacks.
+fn parse_stacks(drawing: &[&str]) -> Result<Vec<String>, Error> {
+    let mut rows = drawing.iter().rev();
+    let count = rows.next().map(|labels| labels.split_whitespace().count()).unwrap_or(0);
+    if count == 0 {
+        return Err(Error::new("Expected a stack drawing above the moves"));
+    }
+    let mut stacks = vec![String::new(); count];
+    for row in rows {
+        for (i, c) in row.chars().skip(1).step_by(4).enumerate() {
+            if c.is_ascii_uppercase() && i < count {
+                stacks[i].push(c);
+            } else if c != ' ' {
+                return Err(Error::new(format!("Unexpected crate {:?} in {:?}", c, row)));
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+// Also moves the crates in `heights`, so a move taking more crates than its
+// stack holds is caught here rather than in the parts.
+fn parse_move(m: &str, heights: &mut [usize]) -> Result<Move, Error> {
+    let stacks = heights.len();
+    let v = numbers::<usize>(m)?;
+    if v.len() != 3 || !(1..=stacks).contains(&v[1]) || !(1..=stacks).contains(&v[2]) {
+        return Err(Error::new(format!("Expected \"move N from A to B\", got {:?}", m)));
+    }
+    let m = Move { count: v[0], from: v[1] - 1, to: v[2] - 1 };
+    if m.count > heights[m.from] {
+        let message = format!("Stack {} only holds {} crates", v[1], heights[m.from]);
+        return Err(Error::new(message));
+    }
+    heights[m.from] -= m.count;
+    heights[m.to] += m.count;
+    Ok(m)
+}
+
+fn top_crates(stacks: &[String]) -> String {
+    stacks.iter().filter_map(|stack| stack.chars().last()).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = Crates;
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Crates, Error> {
+        let lines: Vec<&str> = input.lines().collect();
+        let Some(blank) = lines.iter().position(|line| line.is_empty()) else {
+            return Err(Error::new("Expected a stack drawing and a blank line before the moves"));
+        };
+        let stacks = parse_stacks(&lines[..blank])?;
+        let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.len()).collect();
+        let moves = (blank + 1..lines.len())
+            .map(|i| parse_move(lines[i], &mut heights).map_err(|e| e.at_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+        Ok(Crates { stacks, moves })
+    }
+
+    fn part1(crates: &Crates) -> String {
+        let mut stacks = crates.stacks.clone();
+        for m in &crates.moves {
+            for _ in 0..(m.count) {
+                let c = stacks[m.from].pop().unwrap();
+                stacks[m.to].push(c);
+            }
+        }
+        top_crates(&stacks)
+    }
+
+    fn part2(crates: &Crates) -> String {
+        let mut stacks = crates.stacks.clone();
+        for m in &crates.moves {
+            let at = stacks[m.from].len() - m.count;
+            let v = stacks[m.from].split_off(at);
+            stacks[m.to] += &v;
+        }
+        top_crates(&stacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn example() {
+        let crates = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&crates), "CMZ");
+        assert_eq!(Day5::part2(&crates), "MCD");
+    }
+
+    #[test]
+    fn rejects_unknown_stack() {
+        let err = Day5::parse(&EXAMPLE.replace("to 3", "to 4")).err().unwrap();
+        assert_eq!(err.line(), Some(7));
+    }
+
+    #[test]
+    fn rejects_move_from_short_stack() {
+        let input = EXAMPLE.replace("move 1 from 2", "move 5 from 1");
+        assert_eq!(Day5::parse(&input).err().unwrap().line(), Some(6));
+        // Stack 2 is empty after the third move.
+        let input = EXAMPLE.replace("move 1 from 1 to 2", "move 2 from 2 to 3");
+        assert_eq!(Day5::parse(&input).err().unwrap().line(), Some(9));
+    }
+
+    #[test]
+    fn rejects_oversized_count() {
+        let input = EXAMPLE.replace("move 1 from 2", "move 99999999999999999999999 from 2");
+        assert_eq!(Day5::parse(&input).err().unwrap().line(), Some(6));
+    }
+
+    #[test]
+    fn rejects_missing_drawing() {
+        let moves_only = EXAMPLE.split("\n\n").nth(1).unwrap();
+        for input in ["", moves_only, &format!("\n{}", moves_only)] {
+            let err = Day5::parse(input).err().unwrap();
+            assert!(err.message().starts_with("Expected a stack drawing"), "{:?}", input);
+        }
+    }
+}
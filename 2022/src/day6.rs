@@ -0,0 +1,64 @@
+use aoc::solution::{Error, Solution};
+
+fn solution(content: &[u8], marker_size: usize) -> usize {
+    let mut ans = 0;
+    let mut letters = [0; 26];
+    let mut p = 0;
+    letters[(content[p] - b'a') as usize] = 1;
+
+    for i in 1..content.len() {
+        while letters[(content[i] - b'a') as usize] != 0 {
+            letters[(content[p] - b'a') as usize] -= 1;
+            p += 1;
+        }
+        letters[(content[i] - b'a') as usize] += 1;
+        if i - p == marker_size {
+            ans = i + 1;
+            break;
+        }
+    }
+    ans
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Vec<u8>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<u8>, Error> {
+        let signal = input.trim_end();
+        if signal.is_empty() || !signal.bytes().all(|c| c.is_ascii_lowercase()) {
+            return Err(Error::new("Expected a line of lowercase letters"));
+        }
+        Ok(signal.bytes().collect())
+    }
+
+    fn part1(signal: &Vec<u8>) -> usize {
+        solution(signal, 3)
+    }
+
+    fn part2(signal: &Vec<u8>) -> usize {
+        solution(signal, 13)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        for (input, start_of_packet, start_of_message) in [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ] {
+            let signal = Day6::parse(input).unwrap();
+            assert_eq!(Day6::part1(&signal), start_of_packet);
+            assert_eq!(Day6::part2(&signal), start_of_message);
+        }
+    }
+}
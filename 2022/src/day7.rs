@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use aoc::solution::{Error, Solution};
+
+pub struct Dir {
+    name: String,
+    // File sizes by name.
+    files: HashMap<String, i32>,
+    dirs: HashMap<String, Dir>,
+}
+
+impl Dir {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            files: HashMap::new(),
+            dirs: HashMap::new(),
+        }
+    }
+}
+
+fn get_top_dir<'a>(current_stack: &[String], root: &'a mut Dir) -> &'a mut Dir {
+    let mut c = root;
+    for c_st_dir in current_stack {
+        if c_st_dir == "/" {
+            continue;
+        }
+        c = c.dirs.get_mut(c_st_dir).unwrap();
+    }
+    c
+}
+
+fn count_valid_dir_size(total: &mut i32, root: &Dir) -> i32 {
+    let mut count: i32 = root.files.values().sum();
+    for v in root.dirs.values() {
+        count += count_valid_dir_size(total, v);
+    }
+    if count <= 100000 {
+        *total += count;
+    }
+    count
+}
+
+fn find_dir_to_remove_size(dirs: &mut Vec<i32>, root: &Dir) -> i32 {
+    let mut count: i32 = root.files.values().sum();
+    for v in root.dirs.values() {
+        count += find_dir_to_remove_size(dirs, v);
+    }
+    dirs.push(count);
+    count
+}
+
+fn parse_line(line: &str, current_stack: &mut Vec<String>, root: &mut Dir) -> Result<(), Error> {
+    let text: Vec<&str> = line.split_whitespace().collect();
+    match text[..] {
+        ["$", "cd", "/"] => {
+            current_stack.clear();
+            current_stack.push("/".to_string());
+        }
+        ["$", "cd", ".."] => {
+            if current_stack.len() == 1 {
+                return Err(Error::new("Cannot leave the root directory"));
+            }
+            current_stack.pop();
+        }
+        ["$", "cd", name] => {
+            let Some(dir) = get_top_dir(current_stack, root).dirs.get(name) else {
+                return Err(Error::new(format!("Directory {:?} has not been listed", name)));
+            };
+            current_stack.push(dir.name.clone());
+        }
+        ["$", "ls"] => {}
+        ["dir", name] => {
+            get_top_dir(current_stack, root)
+                .dirs
+                .entry(name.to_string())
+                .or_insert_with(|| Dir::new(name.to_string()));
+        }
+        [size, name] => {
+            let filesize = size.parse::<i32>()?;
+            get_top_dir(current_stack, root).files.entry(name.to_string()).or_insert(filesize);
+        }
+        _ => return Err(Error::new(format!("Unrecognized line {:?}", line))),
+    }
+    Ok(())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    // The root directory.
+    type Parsed = Dir;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Dir, Error> {
+        let mut root = Dir::new("/".to_string());
+        let mut current_stack = vec!["/".to_string()];
+        for (i, line) in input.lines().enumerate() {
+            parse_line(line, &mut current_stack, &mut root).map_err(|e| e.at_line(i + 1))?;
+        }
+        Ok(root)
+    }
+
+    fn part1(root: &Dir) -> i32 {
+        let mut part1 = 0;
+        count_valid_dir_size(&mut part1, root);
+        part1
+    }
+
+    fn part2(root: &Dir) -> i32 {
+        let mut part2 = -1;
+        let mut dirs = Vec::<i32>::new();
+        let total = 70000000 - find_dir_to_remove_size(&mut dirs, root);
+        for dir in dirs {
+            if total + dir >= 30000000
+                && (part2 > dir || part2 == -1) {
+                    part2 = dir;
+                }
+        }
+        part2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn example() {
+        let root = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&root), 95437);
+        assert_eq!(Day7::part2(&root), 24933642);
+    }
+
+    #[test]
+    fn rejects_unlisted_directory() {
+        assert_eq!(Day7::parse("$ cd /\n$ cd a").err().unwrap().line(), Some(2));
+    }
+}
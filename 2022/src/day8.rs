@@ -1,7 +1,10 @@
-use std::fs;
 use std::cmp::max;
 
-fn part1(trees: &Vec<Vec<i32>>, included: &mut Vec<Vec<bool>>) {
+use aoc::parsing::lines;
+use aoc::solution::{Error, Solution};
+
+fn part1(trees: &[Vec<i32>]) -> i32 {
+    let mut included = vec![vec![false; trees[0].len()]; trees.len()];
     for r in 0..trees.len() {
         let mut i = 0;
         let mut prev1 = -1;
@@ -41,16 +44,15 @@ fn part1(trees: &Vec<Vec<i32>>, included: &mut Vec<Vec<bool>>) {
     let mut ans = 0;
     for row in included {
         for v in row {
-            if *v {
+            if v {
                 ans += 1;
             }
         }
     }
-
-   print!("Part1: {}", ans);
+    ans
 }
 
-fn part2(trees: &Vec<Vec<i32>>) {
+fn part2(trees: &[Vec<i32>]) -> i32 {
     let mut ans = 0;
     for i in 0..trees.len() {
         for j in 0..trees[i].len() {
@@ -85,25 +87,49 @@ fn part2(trees: &Vec<Vec<i32>>) {
             ans = max(ans, cnt1 * cnt2 * cnt3 * cnt4);
         }
     }
-    print!("Part2: {}", ans);
+    ans
+}
+
+fn parse_row(line: &str) -> Result<Vec<i32>, Error> {
+    line.chars()
+        .map(|tree| match tree.to_digit(10) {
+            Some(height) => Ok(height as i32),
+            None => Err(Error::new(format!("Tree heights must be digits, got {:?}", tree))),
+        })
+        .collect()
 }
 
-fn main() {
-    let content = fs::read_to_string("input").expect("File 'input' not found!");
-    let mut trees = Vec::new();
-    let mut included = Vec::new();
-    for line in content.lines() {
-        let mut col = Vec::new();
-        let mut in_col = Vec::new();
-        for tree in line.chars() {
-            col.push((tree as i32) - '0' as i32);
-            in_col.push(false);
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, Error> {
+        let trees = lines(input, parse_row)?;
+        if trees.is_empty() || trees.iter().any(|row| row.is_empty() || row.len() != trees[0].len()) {
+            return Err(Error::new("Expected a non-empty rectangle of trees"));
         }
-        trees.push(col);
-        included.push(in_col);
+        Ok(trees)
+    }
+
+    fn part1(trees: &Vec<Vec<i32>>) -> i32 {
+        part1(trees)
     }
-    
-    part1(&trees, &mut included);
-    println!("");
-    part2(&trees);
-}
\ No newline at end of file
+
+    fn part2(trees: &Vec<Vec<i32>>) -> i32 {
+        part2(trees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let trees = Day8::parse("30373\n25512\n65332\n33549\n35390").unwrap();
+        assert_eq!(Day8::part1(&trees), 21);
+        assert_eq!(Day8::part2(&trees), 8);
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::HashSet;
+
+use aoc::grid::{Dir, Point};
+use aoc::parsing::lines;
+use aoc::solution::{Error, Solution};
+
+struct Rope {
+  knots: Vec<Point>,
+}
+
+impl Rope {
+  fn new(len: usize) -> Self {
+    Self {
+      knots: vec![Point::default(); len],
+    }
+  }
+
+  fn follow(&mut self, idx: usize) {
+    if idx == self.knots.len() {
+      return;
+    }
+
+    let dist = self.knots[idx - 1] - self.knots[idx];
+    if dist.x.abs() > 1 || dist.y.abs() > 1 {
+      self.knots[idx] += dist.signum();
+      self.follow(idx + 1);
+    }
+  }
+
+  fn move_rope(&mut self, dir: Dir) -> Point {
+    self.knots[0] += dir.delta();
+    self.follow(1);
+    self.knots[self.knots.len() - 1]
+  }
+}
+
+fn get_move(line: &str) -> Result<(Dir, i32), Error> {
+  let (dir, cnt) = line.split_once(' ').ok_or_else(|| Error::new("Move is missing a count!"))?;
+  let dir = Dir::from_letter(dir).ok_or_else(|| Error::new("Direction is not supported!"))?;
+  Ok((dir, cnt.parse::<i32>()?))
+}
+
+fn tail_positions(moves: &[(Dir, i32)], len: usize) -> usize {
+  let mut pos = HashSet::new();
+  let mut rope = Rope::new(len);
+  pos.insert(Point::default());
+
+  for &(dir, cnt) in moves {
+    for _ in 0..cnt {
+      pos.insert(rope.move_rope(dir));
+    }
+  }
+  pos.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+  type Parsed = Vec<(Dir, i32)>;
+  type Answer = usize;
+
+  fn parse(input: &str) -> Result<Self::Parsed, Error> {
+    lines(input, get_move)
+  }
+
+  fn part1(moves: &Self::Parsed) -> usize {
+    tail_positions(moves, 2)
+  }
+
+  fn part2(moves: &Self::Parsed) -> usize {
+    tail_positions(moves, 10)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SMALL: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+  const LARGE: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
+
+  #[test]
+  fn short_rope_matches_example() {
+    assert_eq!(Day9::part1(&Day9::parse(SMALL).unwrap()), 13);
+  }
+
+  #[test]
+  fn long_rope_matches_examples() {
+    assert_eq!(Day9::part2(&Day9::parse(SMALL).unwrap()), 1);
+    assert_eq!(Day9::part2(&Day9::parse(LARGE).unwrap()), 36);
+  }
+
+  #[test]
+  fn rejects_unknown_direction() {
+    assert_eq!(Day9::parse("R 4\nX 2").err().unwrap().line(), Some(2));
+  }
+}
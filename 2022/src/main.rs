@@ -0,0 +1,52 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use aoc::solution::{run, Error, Report};
+use year2022::*;
+
+// Usage: year2022 DAY [INPUT]
+//
+// Runs one day's solution against INPUT, by default the file "input" in the
+// current directory, and prints both answers with the time of each stage.
+
+type Runner = fn(&str) -> Result<Report, Error>;
+
+const DAYS: [Runner; 13] = [
+    run::<day1::Day1>,
+    run::<day2::Day2>,
+    run::<day3::Day3>,
+    run::<day4::Day4>,
+    run::<day5::Day5>,
+    run::<day6::Day6>,
+    run::<day7::Day7>,
+    run::<day8::Day8>,
+    run::<day9::Day9>,
+    run::<day10::Day10>,
+    run::<day11::Day11>,
+    run::<day12::Day12>,
+    run::<day13::Day13>,
+];
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let day = args.first().and_then(|s| s.parse::<usize>().ok());
+    let (Some(day @ 1..=13), None) = (day, args.get(2)) else {
+        eprintln!("Usage: year2022 DAY [INPUT] (DAY is 1-{})", DAYS.len());
+        process::exit(1);
+    };
+
+    let path = args.get(1).map_or("input", String::as_str);
+    let input = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Cannot read {}: {}", path, e);
+        process::exit(1);
+    });
+
+    match DAYS[day - 1](&input) {
+        Ok(report) => print!("{}", report),
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::bigint::BigInt;
+
+// Codec for balanced numeral systems, where each symbol stands for a digit
+// value in -offset..base - offset and base is the number of symbols. SNAFU
+// uses "=-012" with offset 2, so '=' is -2 and "1=" is 1 * 5 - 2 = 3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalancedBase {
+    symbols: Vec<char>,
+    offset: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BalancedError {
+    Empty,
+    InvalidSymbol { position: usize, symbol: char },
+}
+
+impl fmt::Display for BalancedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BalancedError::Empty => write!(f, "Empty string"),
+            BalancedError::InvalidSymbol { position, symbol } => {
+                write!(f, "Invalid symbol {:?} at position {}", symbol, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BalancedError {}
+
+impl BalancedBase {
+    /// `symbols` lists the digits from lowest to highest value and `offset` is
+    /// the index of the zero digit. It must have a digit on either side, or
+    /// numbers of one sign could not be written.
+    pub fn new(symbols: &str, offset: usize) -> Self {
+        let symbols: Vec<char> = symbols.chars().collect();
+        assert!(symbols.len() >= 3, "Need at least three symbols");
+        assert!(0 < offset && offset < symbols.len() - 1, "Zero digit offset out of range");
+        for (i, c) in symbols.iter().enumerate() {
+            assert!(!symbols[..i].contains(c), "Duplicate symbol {:?}", c);
+        }
+
+        Self { symbols, offset }
+    }
+
+    /// Balanced quinary with digits "=-012", as in the SNAFU puzzle.
+    pub fn snafu() -> Self {
+        Self::new("=-012", 2)
+    }
+
+    /// Balanced ternary with digits "-0+".
+    pub fn ternary() -> Self {
+        Self::new("-0+", 1)
+    }
+
+    pub fn base(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn decode(&self, s: &str) -> Result<BigInt, BalancedError> {
+        if s.is_empty() {
+            return Err(BalancedError::Empty);
+        }
+
+        // Accumulate the positive and negative digits separately so every
+        // intermediate value stays non-negative.
+        let base = BigInt::from_u64(self.base() as u64);
+        let mut positive = BigInt::zero();
+        let mut negative = BigInt::zero();
+        for (position, symbol) in s.chars().enumerate() {
+            let index = self
+                .symbols
+                .iter()
+                .position(|&c| c == symbol)
+                .ok_or(BalancedError::InvalidSymbol { position, symbol })?;
+
+            positive = &positive * &base;
+            negative = &negative * &base;
+            if index >= self.offset {
+                positive = &positive + &BigInt::from_u64((index - self.offset) as u64);
+            } else {
+                negative = &negative + &BigInt::from_u64((self.offset - index) as u64);
+            }
+        }
+
+        Ok(&positive - &negative)
+    }
+
+    pub fn encode(&self, n: &BigInt) -> String {
+        let base = BigInt::from_u64(self.base() as u64);
+        let top = BigInt::from_u64((self.base() - self.offset) as u64);
+
+        let mut n = n.clone();
+        let mut symbols = Vec::new();
+        loop {
+            // Pick the digit congruent to n, shifted into -offset..base - offset.
+            let mut digit = n.rem_euclid(&base);
+            if digit >= top {
+                digit = &digit - &base;
+            }
+            n = (&n - &digit).div(&base);
+
+            let index = (&digit + &BigInt::from_u64(self.offset as u64)).to_usize().unwrap();
+            symbols.push(self.symbols[index]);
+
+            if n == BigInt::zero() {
+                break;
+            }
+        }
+
+        symbols.iter().rev().collect()
+    }
+
+    pub fn add(&self, a: &str, b: &str) -> Result<String, BalancedError> {
+        Ok(self.encode(&(&self.decode(a)? + &self.decode(b)?)))
+    }
+
+    pub fn sub(&self, a: &str, b: &str) -> Result<String, BalancedError> {
+        Ok(self.encode(&(&self.decode(a)? - &self.decode(b)?)))
+    }
+
+    pub fn mul(&self, a: &str, b: &str) -> Result<String, BalancedError> {
+        Ok(self.encode(&(&self.decode(a)? * &self.decode(b)?)))
+    }
+
+    pub fn neg(&self, a: &str) -> Result<String, BalancedError> {
+        Ok(self.encode(&(&BigInt::zero() - &self.decode(a)?)))
+    }
+
+    /// Sum of all numbers, e.g. every line of a SNAFU puzzle input.
+    pub fn sum<'a>(
+        &self,
+        items: impl IntoIterator<Item = &'a str>,
+    ) -> Result<String, BalancedError> {
+        let mut total = BigInt::zero();
+        for item in items {
+            total = &total + &self.decode(item)?;
+        }
+        Ok(self.encode(&total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(codec: &BalancedBase, n: i64, text: &str) {
+        assert_eq!(codec.encode(&BigInt::from(n)), text);
+        assert_eq!(codec.decode(text), Ok(BigInt::from(n)));
+    }
+
+    #[test]
+    fn snafu_round_trips() {
+        let snafu = BalancedBase::snafu();
+        let cases = [
+            (0, "0"),
+            (1, "1"),
+            (3, "1="),
+            (8, "2="),
+            (2022, "1=11-2"),
+            (314159265, "1121-1110-1=0"),
+        ];
+        for (n, text) in cases {
+            round_trip(&snafu, n, text);
+        }
+        let total = snafu.sum(["1=-0-2", "12111", "2=0="]).unwrap();
+        assert_eq!(snafu.decode(&total), Ok(BigInt::from(1747 + 906 + 198)));
+    }
+
+    #[test]
+    fn ternary_round_trips_negative_values() {
+        let ternary = BalancedBase::ternary();
+        for (n, text) in [(-1, "-"), (-2, "-+"), (5, "+--"), (-5, "-++")] {
+            round_trip(&ternary, n, text);
+        }
+        let big = BigInt::from(-7i64).pow(40);
+        let big = -&big;
+        assert_eq!(ternary.decode(&ternary.encode(&big)), Ok(big));
+    }
+
+    #[test]
+    fn rejects_invalid_symbols() {
+        assert_eq!(BalancedBase::snafu().decode(""), Err(BalancedError::Empty));
+        let err = BalancedBase::snafu().decode("1x");
+        assert_eq!(err, Err(BalancedError::InvalidSymbol { position: 1, symbol: 'x' }));
+    }
+
+    #[test]
+    #[should_panic(expected = "offset out of range")]
+    fn rejects_zero_offset() {
+        BalancedBase::new("0123456789", 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "offset out of range")]
+    fn rejects_top_offset() {
+        BalancedBase::new("abc", 2);
+    }
+}
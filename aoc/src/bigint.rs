@@ -0,0 +1,2439 @@
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Sub, Mul, Div, Rem, Shl, Shr, BitAnd, BitOr, BitXor, Not, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign, RemAssign};
+use std::str::FromStr;
+
+const BASE: u64 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+
+// Below this many limbs (on the shorter operand) schoolbook multiplication
+// beats Karatsuba's extra additions and allocations.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+// From this many limbs in both the divisor and the quotient, division by a
+// Newton reciprocal (a few Karatsuba products) beats Algorithm D.
+const NEWTON_THRESHOLD: usize = 1000;
+
+// Up to this many limbs (or chunks) radix conversion runs limb by limb;
+// above it, it splits in halves through a tree of powers of the radix. That
+// is only as fast as the multiplications and divisions it is made of: about
+// n^1.6 with Karatsuba, not the near-linear time of FFT-based libraries.
+const CONVERSION_THRESHOLD: usize = 64;
+
+// Canonical form, relied on by the derived Eq and Hash: at least one limb,
+// every limb below BASE, no leading zero limbs, and zero is never negative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    digits: Vec<u32>,
+    is_negative: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    Empty,
+    // `position` counts chars from the start of the input.
+    InvalidDigit { position: usize, char: char },
+    InvalidRadix(u32),
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseBigIntError::Empty => write!(f, "Empty string"),
+            ParseBigIntError::InvalidDigit { position, char } => {
+                write!(f, "Invalid digit {:?} at position {}", char, position)
+            }
+            ParseBigIntError::InvalidRadix(radix) => {
+                write!(f, "Radix must be in 2..=36, got {}", radix)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromBigIntError;
+
+impl fmt::Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BigInt out of range for the target integer type")
+    }
+}
+
+impl std::error::Error for TryFromBigIntError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeRootError;
+
+impl fmt::Display for NegativeRootError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Root of a negative number")
+    }
+}
+
+impl std::error::Error for NegativeRootError {}
+
+// Parsing is strict by default: no leading `+` and no surrounding whitespace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub allow_plus: bool,
+    pub trim_whitespace: bool,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        Self {
+            digits: vec![0],
+            is_negative: false,
+        }
+    }
+
+    pub fn one() -> Self {
+        Self {
+            digits: vec![1],
+            is_negative: false,
+        }
+    }
+
+    pub fn from_u64(mut n: u64) -> Self {
+        if n == 0 {
+            return Self::zero();
+        }
+
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push((n % BASE) as u32);
+            n /= BASE;
+        }
+
+        Self::from_parts(digits, false)
+    }
+
+    /// Parses an optionally negative number in `radix` (2..=36). Digits past 9
+    /// are letters in either case. A `0b`/`0o`/`0x` prefix matching the radix
+    /// and `_` separators between digits are accepted: "-0xdead_beef". "-0"
+    /// parses as zero.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        Self::from_str_with(s, radix, ParseOptions::default())
+    }
+
+    pub fn from_str_with(
+        s: &str,
+        radix: u32,
+        options: ParseOptions,
+    ) -> Result<Self, ParseBigIntError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseBigIntError::InvalidRadix(radix));
+        }
+
+        let chars: Vec<char> = s.chars().collect();
+        let mut start = 0;
+        let mut end = chars.len();
+        if options.trim_whitespace {
+            while start < end && chars[start].is_whitespace() {
+                start += 1;
+            }
+            while end > start && chars[end - 1].is_whitespace() {
+                end -= 1;
+            }
+        }
+
+        let mut is_negative = false;
+        match chars.get(start) {
+            Some('-') => {
+                is_negative = true;
+                start += 1;
+            }
+            Some('+') if options.allow_plus => start += 1,
+            _ => {}
+        }
+
+        let prefix = match radix {
+            2 => Some('b'),
+            8 => Some('o'),
+            16 => Some('x'),
+            _ => None,
+        };
+        if let Some(prefix) = prefix {
+            if end - start >= 2
+                && chars[start] == '0'
+                && chars[start + 1].to_ascii_lowercase() == prefix
+            {
+                start += 2;
+            }
+        }
+
+        if start == end {
+            return Err(ParseBigIntError::Empty);
+        }
+
+        let mut values = Vec::with_capacity(end - start);
+        for (position, &c) in chars.iter().enumerate().take(end).skip(start) {
+            let invalid = ParseBigIntError::InvalidDigit { position, char: c };
+            if c == '_' {
+                // Separators only go between two digits.
+                if position == start || chars[position - 1] == '_' || position + 1 == end {
+                    return Err(invalid);
+                }
+            } else {
+                let value = c.to_digit(radix).ok_or(invalid)?;
+                values.push(value as u8);
+            }
+        }
+
+        let digits = if radix == 10 {
+            values
+                .rchunks(BASE_DIGITS)
+                .map(|chunk| chunk.iter().fold(0, |acc, &d| acc * 10 + d as u32))
+                .collect()
+        } else {
+            digits_from_radix(&values, radix)
+        };
+
+        Ok(Self::from_parts(digits, is_negative))
+    }
+
+    /// Lowercase digits in `radix` (2..=36), with a leading `-` for negatives
+    /// and no prefix.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let magnitude = self.magnitude_str_radix(radix);
+        if self.is_negative {
+            format!("-{}", magnitude)
+        } else {
+            magnitude
+        }
+    }
+
+    fn magnitude_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "Radix must be in 2..=36, got {}", radix);
+
+        let (chunk_len, chunk_base) = radix_chunk(radix);
+        let chunks = to_chunks(&self.digits, chunk_base as u64);
+
+        let mut s = String::new();
+        for (i, &chunk) in chunks.iter().rev().enumerate() {
+            let mut buf = Vec::with_capacity(chunk_len);
+            let mut chunk = chunk;
+            while chunk > 0 || buf.is_empty() || (i > 0 && buf.len() < chunk_len) {
+                buf.push(std::char::from_digit(chunk % radix, radix).unwrap());
+                chunk /= radix;
+            }
+            s.extend(buf.iter().rev());
+        }
+
+        if s.is_empty() {
+            s.push('0');
+        }
+        s
+    }
+
+    // self += (-1)^other_negative * |other|, reusing self's buffer.
+    fn add_signed_assign(&mut self, other: &[u32], other_negative: bool) {
+        let other = trimmed(other);
+        if self.is_negative == other_negative {
+            let len = std::cmp::max(self.digits.len(), other.len()) + 1;
+            self.digits.resize(len, 0);
+            add_assign_digits(&mut self.digits, other);
+        } else if cmp_digits(&self.digits, other) != Ordering::Less {
+            sub_assign_digits(&mut self.digits, other);
+        } else {
+            // |other| > |self|, so the result takes the sign of other.
+            self.digits.resize(other.len(), 0);
+            rsub_assign_digits(&mut self.digits, other);
+            self.is_negative = other_negative;
+        }
+
+        self.normalize();
+    }
+
+    // self *= n for |n| < 2^32, in place.
+    fn mul_small_assign(&mut self, n: u32, negative: bool) {
+        self.digits.extend([0, 0]);
+        mul_small(&mut self.digits, n);
+        self.is_negative ^= negative;
+        self.normalize();
+    }
+
+    // Truncating self /= n for 0 < |n| < 2^32, in place. Returns the
+    // remainder's magnitude; it takes the sign of the dividend.
+    fn div_rem_small_assign(&mut self, n: u32, negative: bool) -> u32 {
+        if n == 0 {
+            panic!("Division by zero");
+        }
+        let remainder = div_rem_small(&mut self.digits, n);
+        self.is_negative ^= negative;
+        self.normalize();
+        remainder
+    }
+
+    // Builds a value from raw limbs, restoring the canonical form.
+    fn from_parts(digits: Vec<u32>, is_negative: bool) -> Self {
+        let mut result = Self {
+            digits,
+            is_negative,
+        };
+        result.normalize();
+        result
+    }
+
+    // Restores the canonical form: no leading zero limbs and no negative zero.
+    fn normalize(&mut self) {
+        trim(&mut self.digits);
+        if self.digits == [0] {
+            self.is_negative = false;
+        }
+        self.check_invariants();
+    }
+
+    fn check_invariants(&self) {
+        debug_assert!(!self.digits.is_empty(), "BigInt without limbs");
+        debug_assert!(
+            self.digits.len() == 1 || self.digits.last() != Some(&0),
+            "BigInt with a leading zero limb"
+        );
+        debug_assert!(self.digits.iter().all(|&d| (d as u64) < BASE), "BigInt limb out of range");
+        debug_assert!(!(self.is_negative && self.digits == [0]), "Negative zero BigInt");
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.add_signed_assign(&other.digits, other.is_negative);
+        result
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        result.add_signed_assign(&other.digits, !other.is_negative);
+        result
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        let digits = mul_digits(&self.digits, &other.digits);
+        Self::from_parts(digits, self.is_negative != other.is_negative)
+    }
+
+    /// Remainder of truncating division, i.e. `div_rem(other).1`. It takes the
+    /// sign of `self`, like `%` on the primitive integers: -7 % 3 == -1.
+    pub fn modulo(&self, other: &Self) -> Self {
+        let (_, remainder) = self.div_rem(other);
+        remainder
+    }
+
+    pub fn to_usize(&self) -> Option<usize> {
+        usize::try_from(self).ok()
+    }
+
+    // |self| as a u128, if it fits.
+    fn magnitude_u128(&self) -> Option<u128> {
+        let mut result: u128 = 0;
+
+        // Process digits from most significant to least significant
+        for &digit in self.digits.iter().rev() {
+            // Check if multiplying by 10^9 would overflow
+            result = result.checked_mul(BASE as u128)?.checked_add(digit as u128)?;
+        }
+
+        Some(result)
+    }
+
+    fn from_u128(mut n: u128) -> Self {
+        if n == 0 {
+            return Self::zero();
+        }
+
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push((n % BASE as u128) as u32);
+            n /= BASE as u128;
+        }
+
+        Self::from_parts(digits, false)
+    }
+
+    /// The nearest `f64`, or an infinity when `self` is out of range.
+    pub fn to_f64(&self) -> f64 {
+        // The standard library's float parsing rounds correctly.
+        self.to_string().parse().unwrap()
+    }
+
+    /// `f` truncated toward zero, or `None` for NaN and the infinities.
+    pub fn from_f64(f: f64) -> Option<Self> {
+        if !f.is_finite() {
+            return None;
+        }
+
+        // f == mantissa * 2^exponent, exactly.
+        let bits = f.trunc().to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
+
+        let magnitude = Self::from_u64(mantissa);
+        let magnitude = if exponent >= 0 {
+            magnitude.shl(exponent as usize)
+        } else {
+            magnitude.shr(-exponent as usize)
+        };
+        Some(magnitude.with_sign(f.is_sign_negative()))
+    }
+
+    pub fn div(&self, other: &Self) -> Self {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+
+        let (quotient, _) = self.div_rem(other);
+        quotient
+    }
+
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+
+        let (quotient, remainder) = div_rem_digits(&self.digits, &other.digits);
+        (
+            Self::from_parts(quotient, self.is_negative != other.is_negative),
+            Self::from_parts(remainder, self.is_negative),
+        )
+    }
+
+    /// Floored division, like Python's `//` and `%`: the quotient rounds toward
+    /// negative infinity and the remainder takes the sign of `other`.
+    /// -7 / 3 == (-3, 2), 7 / -3 == (-3, -2).
+    pub fn div_mod_floor(&self, other: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.div_rem(other);
+        if !remainder.is_zero() && remainder.is_negative != other.is_negative {
+            (&quotient - &Self::one(), &remainder + other)
+        } else {
+            (quotient, remainder)
+        }
+    }
+
+    pub fn div_floor(&self, other: &Self) -> Self {
+        self.div_mod_floor(other).0
+    }
+
+    pub fn mod_floor(&self, other: &Self) -> Self {
+        self.div_mod_floor(other).1
+    }
+
+    /// Euclidean division: the remainder is always in 0..|other|, whatever the
+    /// signs. -7 / 3 == (-3, 2), -7 / -3 == (3, 2), 7 / -3 == (-2, 1).
+    pub fn div_euclid(&self, other: &Self) -> Self {
+        let (quotient, remainder) = self.div_rem(other);
+        if !remainder.is_negative {
+            quotient
+        } else if other.is_negative {
+            &quotient + &Self::one()
+        } else {
+            &quotient - &Self::one()
+        }
+    }
+
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        let remainder = self.modulo(other);
+        if remainder.is_negative {
+            &remainder + &other.abs()
+        } else {
+            remainder
+        }
+    }
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+
+        result
+    }
+
+    /// `self^exp mod |modulus|`, always in 0..|modulus|, so negative bases
+    /// reduce the same way as in Python's `pow(b, e, m)` for positive `m`.
+    pub fn modpow(&self, exp: &Self, modulus: &Self) -> Self {
+        if exp.is_negative {
+            panic!("Negative exponent");
+        }
+
+        let modulus = modulus.abs();
+        let mut base = self.rem_euclid(&modulus);
+        let mut result = Self::one().rem_euclid(&modulus);
+        let mut exp = exp.digits.clone();
+
+        while !trimmed(&exp).is_empty() {
+            if div_rem_small(&mut exp, 2) == 1 {
+                result = (&result * &base).modulo(&modulus);
+            }
+            base = (&base * &base).modulo(&modulus);
+        }
+
+        result
+    }
+
+    /// The `x` in 0..|modulus| with `self * x == 1 (mod modulus)`, or `None`
+    /// when `self` and `modulus` are not coprime.
+    pub fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        let modulus = modulus.abs();
+        if modulus.is_zero() {
+            return None;
+        }
+
+        // Extended Euclid, tracking only the coefficient of `self`.
+        let (mut r0, mut r1) = (self.rem_euclid(&modulus), modulus.clone());
+        let (mut x0, mut x1) = (Self::one(), Self::zero());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            let x = &x0 - &(&q * &x1);
+            r0 = std::mem::replace(&mut r1, r);
+            x0 = std::mem::replace(&mut x1, x);
+        }
+
+        if !r0.is_one() {
+            return None;
+        }
+        Some(x0.rem_euclid(&modulus))
+    }
+
+    /// floor(sqrt(self)).
+    pub fn sqrt(&self) -> Result<Self, NegativeRootError> {
+        self.nth_root(2)
+    }
+
+    /// The largest `r` with `r^n <= self`.
+    pub fn nth_root(&self, n: u32) -> Result<Self, NegativeRootError> {
+        if n == 0 {
+            panic!("Zeroth root");
+        }
+        if self.is_negative {
+            return Err(NegativeRootError);
+        }
+        if n == 1 || self <= &Self::one() {
+            return Ok(self.clone());
+        }
+
+        // log2(self) from the top three limbs, good to far more bits than the
+        // float estimate below needs.
+        let len = self.digits.len();
+        let top = self.digits.iter().rev().take(3);
+        let top = top.fold(0.0, |acc, &d| acc * BASE as f64 + d as f64);
+        let log = top.log2() + len.saturating_sub(3) as f64 * (BASE as f64).log2();
+        if log < n as f64 - 1.0 {
+            // self < 2^n, so the root is 1.
+            return Ok(Self::one());
+        }
+
+        // Start at or above the root, from a float estimate when the root is
+        // short and from the root of the top half of its limbs otherwise.
+        let mut x = if log / (n as f64) < 32.0 {
+            Self::from_f64((log / n as f64).exp2() * (1.0 + 1e-9) + 1.0).unwrap()
+        } else {
+            // With self = top * B^nk + low and r the root of top, top < (r + 1)^n,
+            // so self < (top + 1) B^nk <= ((r + 1) B^k)^n.
+            let k = len.div_ceil(n as usize) / 2;
+            let top = Self::from_parts(self.digits[n as usize * k..].to_vec(), false);
+            let root = &top.nth_root(n)? + &Self::one();
+            let mut digits = vec![0; k];
+            digits.extend_from_slice(&root.digits);
+            Self::from_parts(digits, false)
+        };
+
+        // Newton's iteration decreases monotonically from there until it
+        // reaches floor(root).
+        let n_big = Self::from(n);
+        let n_minus_one = Self::from(n - 1);
+        loop {
+            // y = ((n - 1) * x + self / x^(n - 1)) / n
+            let sum = &(&n_minus_one * &x) + &self.div(&x.pow(n - 1));
+            let y = &sum / &n_big;
+            if y >= x {
+                return Ok(x);
+            }
+            x = y;
+        }
+    }
+
+    pub fn is_perfect_square(&self) -> bool {
+        match self.sqrt() {
+            Ok(root) => &root * &root == *self,
+            Err(NegativeRootError) => false,
+        }
+    }
+
+    /// Number of bits needed to represent `|self|`, 0 for zero, like
+    /// Python's `int.bit_length`. Costs a conversion of the whole value to
+    /// binary, as do all the bitwise operations unless noted.
+    pub fn bit_len(&self) -> usize {
+        let binary = to_binary(&self.digits);
+        match binary.last() {
+            Some(&top) => binary.len() * 32 - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Number of set bits in `|self|`, like Python's `int.bit_count`. Costs a
+    /// conversion to binary.
+    pub fn count_ones(&self) -> usize {
+        to_binary(&self.digits).iter().map(|limb| limb.count_ones() as usize).sum()
+    }
+
+    /// Bit `n` of the infinite two's-complement representation, so every
+    /// high bit of a negative number is set. Low bits cost one pass over the
+    /// limbs per 31 bits of `n` and bits past the top are free; only the
+    /// middle of a large value costs a conversion to binary.
+    pub fn bit(&self, n: usize) -> bool {
+        // BASE < 2^30, so no magnitude reaches bit 30 * limbs.
+        if n >= 30 * self.digits.len() {
+            return self.is_negative;
+        }
+        if n >= 32 * CONVERSION_THRESHOLD {
+            // Low limbs of a two's complement only depend on low limbs.
+            let limbs = self.to_twos_complement(n / 32 + 1);
+            return limbs[n / 32] >> (n % 32) & 1 == 1;
+        }
+
+        // Bit n is the parity of floor(self / 2^n), and for negatives
+        // floor(-x / 2^n) == -ceil(x / 2^n).
+        let mut quotient = self.digits.clone();
+        let mut exact = true;
+        let mut remaining = n;
+        while remaining > 0 {
+            let shift = remaining.min(31);
+            exact &= div_rem_small(&mut quotient, 1 << shift) == 0;
+            remaining -= shift;
+        }
+        (quotient[0] & 1 == 1) != (self.is_negative && !exact)
+    }
+
+    // Two's complement of `self` in exactly `len` base-2^32 limbs. `len` must
+    // leave room for the sign bit, unless only the low limbs are wanted.
+    fn to_twos_complement(&self, len: usize) -> Vec<u32> {
+        let mut limbs = to_binary(&self.digits);
+        limbs.resize(len, 0);
+        if self.is_negative {
+            // -x == !(x - 1)
+            for limb in limbs.iter_mut() {
+                let (value, borrow) = limb.overflowing_sub(1);
+                *limb = value;
+                if !borrow {
+                    break;
+                }
+            }
+            for limb in limbs.iter_mut() {
+                *limb = !*limb;
+            }
+        }
+        limbs
+    }
+
+    fn from_twos_complement(mut limbs: Vec<u32>, is_negative: bool) -> Self {
+        if is_negative {
+            // x == !(-x) + 1
+            for limb in limbs.iter_mut() {
+                *limb = !*limb;
+            }
+            for limb in limbs.iter_mut() {
+                let (value, carry) = limb.overflowing_add(1);
+                *limb = value;
+                if !carry {
+                    break;
+                }
+            }
+        }
+
+        Self::from_binary(&limbs, is_negative)
+    }
+
+    fn from_binary(limbs: &[u32], is_negative: bool) -> Self {
+        Self::from_parts(from_binary(limbs), is_negative)
+    }
+
+    fn bitwise(&self, other: &Self, op: impl Fn(u32, u32) -> u32) -> Self {
+        let len = std::cmp::max(self.bit_len(), other.bit_len()) / 32 + 1;
+        let a = self.to_twos_complement(len);
+        let b = other.to_twos_complement(len);
+        let limbs = a.iter().zip(&b).map(|(&x, &y)| op(x, y)).collect();
+        let sign = |negative: bool| if negative { u32::MAX } else { 0 };
+        let is_negative = op(sign(self.is_negative), sign(other.is_negative)) != 0;
+        Self::from_twos_complement(limbs, is_negative)
+    }
+
+    /// `self * 2^n`. Costs a conversion to binary and back.
+    pub fn shl(&self, n: usize) -> Self {
+        let mut limbs = vec![0; n / 32];
+        limbs.extend(to_binary(&self.digits));
+        limbs.push(0);
+
+        let shift = n % 32;
+        if shift > 0 {
+            for i in (n / 32..limbs.len()).rev() {
+                let low = if i > 0 { limbs[i - 1] >> (32 - shift) } else { 0 };
+                limbs[i] = limbs[i] << shift | low;
+            }
+        }
+
+        Self::from_binary(&limbs, self.is_negative)
+    }
+
+    /// `self / 2^n` rounded toward negative infinity, matching an arithmetic
+    /// shift of the two's-complement representation: -5 >> 1 == -3. Costs a
+    /// conversion to binary and back.
+    pub fn shr(&self, n: usize) -> Self {
+        // -x >> n == -((x - 1) >> n) - 1
+        let magnitude = if self.is_negative { &self.abs() - &Self::one() } else { self.abs() };
+        let binary = to_binary(&magnitude.digits);
+
+        let skip = n / 32;
+        let shift = n % 32;
+        let mut limbs: Vec<u32> = binary.iter().skip(skip).copied().collect();
+        if shift > 0 {
+            for i in 0..limbs.len() {
+                let high = limbs.get(i + 1).map_or(0, |&next| next << (32 - shift));
+                limbs[i] = limbs[i] >> shift | high;
+            }
+        }
+
+        let shifted = Self::from_binary(&limbs, false);
+        if self.is_negative {
+            (&shifted + &Self::one()).with_sign(true)
+        } else {
+            shifted
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits == [0]
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.digits == [1] && !self.is_negative
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Only the lowest limb matters since BASE is even.
+    pub fn is_even(&self) -> bool {
+        self.digits[0] & 1 == 0
+    }
+
+    /// -1, 0 or 1, like `i64::signum`.
+    pub fn signum(&self) -> Self {
+        if self.is_zero() {
+            Self::zero()
+        } else {
+            Self::one().with_sign(self.is_negative)
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        self.with_sign(false)
+    }
+
+    fn with_sign(&self, negative: bool) -> Self {
+        Self::from_parts(self.digits.clone(), negative)
+    }
+}
+
+// Limb-level helpers. All slices are little-endian base-10^9 magnitudes.
+
+fn trim(digits: &mut Vec<u32>) {
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    if digits.is_empty() {
+        digits.push(0);
+    }
+}
+
+fn trimmed(digits: &[u32]) -> &[u32] {
+    let len = digits.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+    &digits[..len]
+}
+
+fn mul_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = (trimmed(a), trimmed(b));
+    let mut result = vec![0; a.len() + b.len()];
+    mul_acc(&mut result, a, b);
+    result
+}
+
+// acc += a * b. `acc` must have room for a.len() + b.len() limbs.
+fn mul_acc(acc: &mut [u32], a: &[u32], b: &[u32]) {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.is_empty() {
+        return;
+    }
+
+    if b.len() < KARATSUBA_THRESHOLD {
+        schoolbook_acc(acc, a, b);
+    } else if a.len() >= 2 * b.len() {
+        // Unbalanced operands: multiply b by b-sized chunks of a.
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            let offset = i * b.len();
+            mul_acc(&mut acc[offset..], chunk, b);
+        }
+    } else {
+        karatsuba_acc(acc, a, b);
+    }
+}
+
+fn schoolbook_acc(acc: &mut [u32], a: &[u32], b: &[u32]) {
+    for (i, &bd) in b.iter().enumerate() {
+        if bd == 0 {
+            continue;
+        }
+
+        let mut carry = 0u64;
+        for (j, &ad) in a.iter().enumerate() {
+            let t = acc[i + j] as u64 + ad as u64 * bd as u64 + carry;
+            acc[i + j] = (t % BASE) as u32;
+            carry = t / BASE;
+        }
+
+        add_carry(&mut acc[i + a.len()..], carry);
+    }
+}
+
+// a = a1 * B^m + a0, b = b1 * B^m + b0
+// a * b = z2 * B^2m + (z1 - z2 - z0) * B^m + z0, with z1 = (a0 + a1)(b0 + b1)
+fn karatsuba_acc(acc: &mut [u32], a: &[u32], b: &[u32]) {
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m.min(b.len()));
+    let (a0, b0) = (trimmed(a0), trimmed(b0));
+
+    let z0 = mul_digits(a0, b0);
+    let z2 = mul_digits(a1, b1);
+    let mut z1 = mul_digits(&add_digits(a0, a1), &add_digits(b0, b1));
+    sub_assign_digits(&mut z1, &z0);
+    sub_assign_digits(&mut z1, &z2);
+
+    add_assign_digits(acc, trimmed(&z0));
+    add_assign_digits(&mut acc[m..], trimmed(&z1));
+    add_assign_digits(&mut acc[2 * m..], trimmed(&z2));
+}
+
+fn cmp_digits(a: &[u32], b: &[u32]) -> Ordering {
+    let (a, b) = (trimmed(a), trimmed(b));
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+// Magnitude division: returns (quotient, remainder), both untrimmed.
+fn div_rem_digits(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let (u, v) = (trimmed(u), trimmed(v));
+    assert!(!v.is_empty(), "Division by zero");
+
+    if cmp_digits(u, v) == Ordering::Less {
+        return (vec![0], u.to_vec());
+    }
+
+    if v.len() == 1 {
+        let mut quotient = u.to_vec();
+        let remainder = div_rem_small(&mut quotient, v[0]);
+        return (quotient, vec![remainder]);
+    }
+
+    if v.len() >= NEWTON_THRESHOLD && u.len() - v.len() >= NEWTON_THRESHOLD {
+        return div_rem_reciprocal(u, v, &reciprocal(v));
+    }
+
+    knuth_d(u, v)
+}
+
+// B^n as limbs.
+fn base_power(n: usize) -> Vec<u32> {
+    let mut power = vec![0; n + 1];
+    power[n] = 1;
+    power
+}
+
+// About floor(B^2m / v) for a trimmed m-limb v, by Newton's iteration
+// y' = y (2 - v y / B^2m) from a reciprocal of v's top half. Starting below the
+// true value keeps every intermediate non-negative and the result stays below
+// it too, by at most three units.
+fn reciprocal(v: &[u32]) -> Vec<u32> {
+    let m = v.len();
+    if m < NEWTON_THRESHOLD {
+        return div_rem_digits(&base_power(2 * m), v).0;
+    }
+
+    // y0 = floor(B^2t / (top + 1)) * B^s <= B^2m / v. The two guard limbs make
+    // y0 good to more than m/2 limbs, which one step doubles.
+    let t = m.div_ceil(2) + 2;
+    let s = m - t;
+    let mut top = v[s..].to_vec();
+    top.push(0);
+    add_carry(&mut top, 1);
+    let mut y = if trimmed(&top).len() > t { base_power(t) } else { reciprocal(trimmed(&top)) };
+    y.splice(0..0, std::iter::repeat_n(0, s));
+
+    // y += y * (B^2m - v y) / B^2m
+    let mut error = base_power(2 * m);
+    sub_assign_digits(&mut error, trimmed(&mul_digits(v, &y)));
+    let step = mul_digits(&y, &error);
+    y.resize(m + 2, 0);
+    if step.len() > 2 * m {
+        add_assign_digits(&mut y, trimmed(&step[2 * m..]));
+    }
+    y
+}
+
+// Long division in blocks of m = v.len() limbs, given `inverse` from
+// reciprocal(v). Every partial dividend x is below v B^m, so estimating its
+// quotient from the top m + 1 limbs, floor(x / B^(m-1)) * inverse / B^(m+1),
+// comes out a few units low at most.
+fn div_rem_reciprocal(u: &[u32], v: &[u32], inverse: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let m = v.len();
+    let mut quotient = vec![0; u.len()];
+    let mut remainder: Vec<u32> = Vec::new();
+
+    for (block, start) in (0..u.len()).step_by(m).rev().enumerate() {
+        let end = (start + m).min(u.len());
+        let mut x = u[start..end].to_vec();
+        x.extend_from_slice(&remainder);
+
+        let mut q = if block == 0 {
+            // The top block is below B^m, so its quotient is a single limb.
+            vec![0; m + 1]
+        } else {
+            let estimate = mul_digits(&x[m - 1..], inverse);
+            let mut q = estimate.get(m + 1..).map_or(vec![], |q| trimmed(q).to_vec());
+            sub_assign_digits(&mut x, trimmed(&mul_digits(&q, v)));
+            q.resize(m + 1, 0);
+            q
+        };
+        while cmp_digits(&x, v) != Ordering::Less {
+            sub_assign_digits(&mut x, v);
+            add_carry(&mut q, 1);
+        }
+
+        let q = trimmed(&q);
+        quotient[start..start + q.len()].copy_from_slice(q);
+        remainder = trimmed(&x).to_vec();
+    }
+
+    remainder.resize(m, 0);
+    (quotient, remainder)
+}
+
+// digits /= divisor in place, returning the remainder.
+fn div_rem_small(digits: &mut [u32], divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for limb in digits.iter_mut().rev() {
+        let t = remainder * BASE + *limb as u64;
+        *limb = (t / divisor as u64) as u32;
+        remainder = t % divisor as u64;
+    }
+    remainder as u32
+}
+
+// digits *= factor in place. The caller leaves room for the carry.
+fn mul_small(digits: &mut [u32], factor: u32) {
+    let mut carry = 0u64;
+    for limb in digits.iter_mut() {
+        let t = *limb as u64 * factor as u64 + carry;
+        *limb = (t % BASE) as u32;
+        carry = t / BASE;
+    }
+    debug_assert_eq!(carry, 0, "carry out of accumulator");
+}
+
+// Knuth, TAOCP vol. 2, 4.3.1, Algorithm D, for u >= v and v.len() >= 2.
+fn knuth_d(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let n = v.len();
+    let m = u.len() - n;
+
+    // D1: scale both operands so the divisor's top limb is at least BASE / 2.
+    let d = (BASE / (v[n - 1] as u64 + 1)) as u32;
+    let mut v = v.to_vec();
+    mul_small(&mut v, d);
+    let mut u = u.to_vec();
+    u.push(0);
+    mul_small(&mut u, d);
+
+    let (v_top, v_next) = (v[n - 1] as u64, v[n - 2] as u64);
+    let mut quotient = vec![0; m + 1];
+
+    for j in (0..=m).rev() {
+        // D3: estimate the quotient limb from the top two limbs of the window,
+        // then correct it with the next divisor limb. It is off by at most one.
+        let top = u[j + n] as u64 * BASE + u[j + n - 1] as u64;
+        let mut qhat = top / v_top;
+        let mut rhat = top % v_top;
+        while qhat >= BASE || qhat * v_next > rhat * BASE + u[j + n - 2] as u64 {
+            qhat -= 1;
+            rhat += v_top;
+            if rhat >= BASE {
+                break;
+            }
+        }
+
+        // D4: u[j..=j + n] -= qhat * v
+        let mut carry = 0u64;
+        let mut borrow = 0i64;
+        for i in 0..n {
+            let p = qhat * v[i] as u64 + carry;
+            carry = p / BASE;
+            let t = u[i + j] as i64 - (p % BASE) as i64 - borrow;
+            borrow = (t < 0) as i64;
+            u[i + j] = (t + borrow * BASE as i64) as u32;
+        }
+        let t = u[j + n] as i64 - carry as i64 - borrow;
+        let negative = t < 0;
+        u[j + n] = (t + negative as i64 * BASE as i64) as u32;
+
+        // D6: the estimate was one too large, add the divisor back.
+        if negative {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let t = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = (t % BASE) as u32;
+                carry = t / BASE;
+            }
+            u[j + n] = ((u[j + n] as u64 + carry) % BASE) as u32;
+        }
+
+        quotient[j] = qhat as u32;
+    }
+
+    // D8: unscale the remainder.
+    u.truncate(n);
+    div_rem_small(&mut u, d);
+    (quotient, u)
+}
+
+fn add_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(a.len() + 1);
+    result.extend_from_slice(a);
+    result.push(0);
+    add_assign_digits(&mut result, b);
+    result
+}
+
+// acc += b. The caller guarantees the sum fits in `acc`.
+fn add_assign_digits(acc: &mut [u32], b: &[u32]) {
+    let mut carry = 0u64;
+    for (i, &d) in b.iter().enumerate() {
+        let t = acc[i] as u64 + d as u64 + carry;
+        acc[i] = (t % BASE) as u32;
+        carry = t / BASE;
+    }
+
+    add_carry(&mut acc[b.len()..], carry);
+}
+
+fn add_carry(acc: &mut [u32], mut carry: u64) {
+    for limb in acc {
+        if carry == 0 {
+            return;
+        }
+        let t = *limb as u64 + carry;
+        *limb = (t % BASE) as u32;
+        carry = t / BASE;
+    }
+
+    debug_assert_eq!(carry, 0, "carry out of accumulator");
+}
+
+// acc -= b. The caller guarantees acc >= b.
+fn sub_assign_digits(acc: &mut [u32], b: &[u32]) {
+    let mut borrow = 0i64;
+    for (i, a) in acc.iter_mut().enumerate() {
+        if i >= b.len() && borrow == 0 {
+            break;
+        }
+
+        let mut diff = *a as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += BASE as i64;
+            borrow = 1;
+        }
+        *a = diff as u32;
+    }
+
+    debug_assert_eq!(borrow, 0, "subtraction underflow");
+}
+
+// The most digits of `radix` that fit below BASE, and radix^that.
+fn radix_chunk(radix: u32) -> (usize, u32) {
+    let mut len = 0;
+    let mut power = 1u64;
+    while power * radix as u64 <= BASE {
+        power *= radix as u64;
+        len += 1;
+    }
+    (len, power as u32)
+}
+
+// Digit values, most significant first, grouped into radix_chunk(radix) chunks.
+fn digits_from_radix(values: &[u8], radix: u32) -> Vec<u32> {
+    let (chunk_len, chunk_base) = radix_chunk(radix);
+    let chunks: Vec<u32> = values
+        .rchunks(chunk_len)
+        .map(|chunk| chunk.iter().fold(0, |acc, &d| acc * radix + d as u32))
+        .collect();
+    from_chunks(&chunks, chunk_base as u64)
+}
+
+// acc = b - acc. The caller guarantees b >= acc and acc.len() == b.len().
+fn rsub_assign_digits(acc: &mut [u32], b: &[u32]) {
+    let mut borrow = 0i64;
+    for (limb, &d) in acc.iter_mut().zip(b) {
+        let mut diff = d as i64 - *limb as i64 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += BASE as i64;
+            borrow = 1;
+        }
+        *limb = diff as u32;
+    }
+
+    debug_assert_eq!(borrow, 0, "subtraction underflow");
+}
+
+// Conversion between the limbs and little-endian chunks in another base c of
+// at most 2^32, e.g. radix_chunk's power of a radix, or 2^32 for the bitwise
+// operations. Zero converts to no chunks. Short inputs go limb by limb, which
+// is quadratic; long ones split in halves at c^(2^k) from a power tree, so the
+// work is a few big multiplications and reciprocal divisions per level.
+
+// Powers c^(2^k), each the square of the one before, with the reciprocals of
+// the large ones computed on first use.
+struct PowerTree {
+    base: u64,
+    powers: Vec<Vec<u32>>,
+    reciprocals: Vec<OnceCell<Vec<u32>>>,
+}
+
+impl PowerTree {
+    fn new(base: u64) -> Self {
+        let mut first = vec![(base % BASE) as u32, (base / BASE) as u32];
+        trim(&mut first);
+        Self {
+            base,
+            powers: vec![first],
+            reciprocals: vec![OnceCell::new()],
+        }
+    }
+
+    fn grow(&mut self) {
+        let last = self.powers.last().unwrap();
+        let mut next = mul_digits(last, last);
+        trim(&mut next);
+        self.powers.push(next);
+        self.reciprocals.push(OnceCell::new());
+    }
+
+    // (x / c^(2^k), x % c^(2^k))
+    fn div_rem(&self, x: &[u32], k: usize) -> (Vec<u32>, Vec<u32>) {
+        let (x, power) = (trimmed(x), &self.powers[k]);
+        if power.len() < NEWTON_THRESHOLD {
+            return div_rem_digits(x, power);
+        }
+        let inverse = self.reciprocals[k].get_or_init(|| reciprocal(power));
+        div_rem_reciprocal(x, power, inverse)
+    }
+}
+
+// Digits in base c, least significant first. Each level of the split does one
+// division per part at that level's size, so the cost follows division:
+// quadratic below NEWTON_THRESHOLD and Karatsuba-bound, ~n^1.6, above it.
+fn to_chunks(digits: &[u32], base: u64) -> Vec<u32> {
+    let digits = trimmed(digits);
+    if base == BASE {
+        return digits.to_vec();
+    }
+    if digits.len() <= CONVERSION_THRESHOLD {
+        return to_chunks_small(digits, base);
+    }
+
+    let mut tree = PowerTree::new(base);
+    while cmp_digits(tree.powers.last().unwrap(), digits) != Ordering::Greater {
+        tree.grow();
+    }
+    let mut chunks = Vec::new();
+    to_chunks_split(digits, &tree, tree.powers.len() - 2, false, &mut chunks);
+
+    // A high half of zero still leaves its padded low half behind.
+    let len = chunks.iter().rposition(|&chunk| chunk != 0).map_or(0, |i| i + 1);
+    chunks.truncate(len);
+    chunks
+}
+
+// Appends the chunks of x < c^(2^(k+1)), exactly 2^(k+1) of them with `pad`.
+fn to_chunks_split(x: &[u32], tree: &PowerTree, k: usize, pad: bool, chunks: &mut Vec<u32>) {
+    let start = chunks.len();
+    let x = trimmed(x);
+    if x.len() <= CONVERSION_THRESHOLD {
+        chunks.extend(to_chunks_small(x, tree.base));
+    } else {
+        let (high, low) = tree.div_rem(x, k);
+        to_chunks_split(&low, tree, k - 1, true, chunks);
+        to_chunks_split(&high, tree, k - 1, pad, chunks);
+    }
+
+    if pad {
+        chunks.resize(start + (2 << k), 0);
+    }
+}
+
+// Horner's rule in base c over the limbs, most significant first.
+fn to_chunks_small(digits: &[u32], base: u64) -> Vec<u32> {
+    let mut chunks: Vec<u32> = Vec::new();
+    for &digit in trimmed(digits).iter().rev() {
+        let mut carry = digit as u64;
+        for chunk in chunks.iter_mut() {
+            let t = *chunk as u64 * BASE + carry;
+            *chunk = (t % base) as u32;
+            carry = t / base;
+        }
+        while carry > 0 {
+            chunks.push((carry % base) as u32);
+            carry /= base;
+        }
+    }
+    chunks
+}
+
+// The inverse of to_chunks. Its levels multiply instead of divide, so it is
+// Karatsuba-bound, ~n^1.6, at every size above CONVERSION_THRESHOLD.
+fn from_chunks(chunks: &[u32], base: u64) -> Vec<u32> {
+    if base == BASE {
+        return chunks.to_vec();
+    }
+    if chunks.len() <= CONVERSION_THRESHOLD {
+        return from_chunks_small(chunks, base);
+    }
+
+    let mut tree = PowerTree::new(base);
+    while 1 << tree.powers.len() < chunks.len() {
+        tree.grow();
+    }
+    from_chunks_split(chunks, &tree)
+}
+
+// high * c^(2^k) + low, where low is the largest power-of-two count of chunks
+// that leaves high non-empty.
+fn from_chunks_split(chunks: &[u32], tree: &PowerTree) -> Vec<u32> {
+    if chunks.len() <= CONVERSION_THRESHOLD {
+        return from_chunks_small(chunks, tree.base);
+    }
+
+    let k = (chunks.len() - 1).ilog2() as usize;
+    let (low, high) = chunks.split_at(1 << k);
+    let mut digits = mul_digits(&from_chunks_split(high, tree), &tree.powers[k]);
+    add_assign_digits(&mut digits, trimmed(&from_chunks_split(low, tree)));
+    digits
+}
+
+// Horner's rule in base 10^9 over the chunks, most significant first.
+fn from_chunks_small(chunks: &[u32], base: u64) -> Vec<u32> {
+    let mut digits: Vec<u32> = Vec::new();
+    for &chunk in chunks.iter().rev() {
+        let mut carry = chunk as u64;
+        for digit in digits.iter_mut() {
+            let t = *digit as u64 * base + carry;
+            *digit = (t % BASE) as u32;
+            carry = t / BASE;
+        }
+        while carry > 0 {
+            digits.push((carry % BASE) as u32);
+            carry /= BASE;
+        }
+    }
+    digits
+}
+
+fn to_binary(digits: &[u32]) -> Vec<u32> {
+    to_chunks(digits, 1 << 32)
+}
+
+fn from_binary(binary: &[u32]) -> Vec<u32> {
+    let mut digits = from_chunks(binary, 1 << 32);
+    trim(&mut digits);
+    digits
+}
+
+impl Default for BigInt {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+// Operator overloading. Every combination of owned and borrowed operands is
+// accepted; an owned left operand is updated in place when possible.
+impl<'a> AddAssign<&'a BigInt> for BigInt {
+    fn add_assign(&mut self, other: &'a BigInt) {
+        self.add_signed_assign(&other.digits, other.is_negative);
+    }
+}
+
+impl<'a> SubAssign<&'a BigInt> for BigInt {
+    fn sub_assign(&mut self, other: &'a BigInt) {
+        self.add_signed_assign(&other.digits, !other.is_negative);
+    }
+}
+
+impl<'a> MulAssign<&'a BigInt> for BigInt {
+    fn mul_assign(&mut self, other: &'a BigInt) {
+        if other.digits.len() == 1 {
+            self.mul_small_assign(other.digits[0], other.is_negative);
+        } else {
+            *self = BigInt::mul(self, other);
+        }
+    }
+}
+
+impl<'a> DivAssign<&'a BigInt> for BigInt {
+    fn div_assign(&mut self, other: &'a BigInt) {
+        if other.digits.len() == 1 {
+            self.div_rem_small_assign(other.digits[0], other.is_negative);
+        } else {
+            *self = BigInt::div(self, other);
+        }
+    }
+}
+
+impl<'a> RemAssign<&'a BigInt> for BigInt {
+    fn rem_assign(&mut self, other: &'a BigInt) {
+        if other.digits.len() == 1 {
+            let is_negative = self.is_negative;
+            let remainder = self.div_rem_small_assign(other.digits[0], false);
+            self.digits.clear();
+            self.digits.push(remainder);
+            self.is_negative = is_negative;
+            self.normalize();
+        } else {
+            *self = BigInt::modulo(self, other);
+        }
+    }
+}
+
+macro_rules! impl_assign_owned {
+    ($($tr:ident $m:ident),*) => {
+        $(impl $tr for BigInt {
+            fn $m(&mut self, other: BigInt) {
+                self.$m(&other);
+            }
+        })*
+    };
+}
+
+impl_assign_owned!(AddAssign add_assign, SubAssign sub_assign, MulAssign mul_assign,
+    DivAssign div_assign, RemAssign rem_assign);
+
+macro_rules! impl_binary_op {
+    ($($tr:ident $m:ident $assign:ident),*) => {
+        $(impl $tr for BigInt {
+            type Output = BigInt;
+            fn $m(mut self, other: BigInt) -> BigInt {
+                self.$assign(&other);
+                self
+            }
+        }
+
+        impl<'a> $tr<&'a BigInt> for BigInt {
+            type Output = BigInt;
+            fn $m(mut self, other: &'a BigInt) -> BigInt {
+                self.$assign(other);
+                self
+            }
+        }
+
+        impl $tr<BigInt> for &BigInt {
+            type Output = BigInt;
+            fn $m(self, other: BigInt) -> BigInt {
+                BigInt::$m(self, &other)
+            }
+        }
+
+        impl $tr<&BigInt> for &BigInt {
+            type Output = BigInt;
+            fn $m(self, other: &BigInt) -> BigInt {
+                BigInt::$m(self, other)
+            }
+        })*
+    };
+}
+
+impl_binary_op!(Add add add_assign, Sub sub sub_assign, Mul mul mul_assign,
+    Div div div_assign);
+
+// `%` goes through `modulo`, the truncating remainder.
+impl Rem for BigInt {
+    type Output = BigInt;
+    fn rem(mut self, other: BigInt) -> BigInt {
+        self %= &other;
+        self
+    }
+}
+
+impl<'a> Rem<&'a BigInt> for BigInt {
+    type Output = BigInt;
+    fn rem(mut self, other: &'a BigInt) -> BigInt {
+        self %= other;
+        self
+    }
+}
+
+impl Rem<BigInt> for &BigInt {
+    type Output = BigInt;
+    fn rem(self, other: BigInt) -> BigInt {
+        BigInt::modulo(self, &other)
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn rem(self, other: &BigInt) -> BigInt {
+        BigInt::modulo(self, other)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(mut self) -> BigInt {
+        self.is_negative = !self.is_negative;
+        self.normalize();
+        self
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> BigInt {
+        iter.fold(BigInt::zero(), |acc, n| acc + n)
+    }
+}
+
+impl<'a> Sum<&'a BigInt> for BigInt {
+    fn sum<I: Iterator<Item = &'a BigInt>>(iter: I) -> BigInt {
+        iter.fold(BigInt::zero(), |acc, n| acc + n)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> BigInt {
+        iter.fold(BigInt::one(), |acc, n| acc * n)
+    }
+}
+
+impl<'a> Product<&'a BigInt> for BigInt {
+    fn product<I: Iterator<Item = &'a BigInt>>(iter: I) -> BigInt {
+        iter.fold(BigInt::one(), |acc, n| acc * n)
+    }
+}
+
+// Mixed operators with primitives: `big * 7`, `big + 1u64`, `2 - &big`.
+// Operands that fit in a u32 use the in-place single-limb paths.
+macro_rules! impl_primitive_ops {
+    ($($t:ty),*) => {
+        $(impl AddAssign<$t> for BigInt {
+            fn add_assign(&mut self, n: $t) {
+                let (magnitude, negative) = split_sign(n as i128);
+                self.add_signed_assign(&u64_limbs(magnitude), negative);
+            }
+        }
+
+        impl SubAssign<$t> for BigInt {
+            fn sub_assign(&mut self, n: $t) {
+                let (magnitude, negative) = split_sign(n as i128);
+                self.add_signed_assign(&u64_limbs(magnitude), !negative);
+            }
+        }
+
+        impl MulAssign<$t> for BigInt {
+            fn mul_assign(&mut self, n: $t) {
+                let (magnitude, negative) = split_sign(n as i128);
+                match u32::try_from(magnitude) {
+                    Ok(small) => self.mul_small_assign(small, negative),
+                    Err(_) => *self *= &BigInt::from(n),
+                }
+            }
+        }
+
+        impl DivAssign<$t> for BigInt {
+            fn div_assign(&mut self, n: $t) {
+                let (magnitude, negative) = split_sign(n as i128);
+                match u32::try_from(magnitude) {
+                    Ok(small) => {
+                        self.div_rem_small_assign(small, negative);
+                    }
+                    Err(_) => *self /= &BigInt::from(n),
+                }
+            }
+        }
+
+        impl RemAssign<$t> for BigInt {
+            fn rem_assign(&mut self, n: $t) {
+                *self %= &BigInt::from(n);
+            }
+        }
+
+        impl_primitive_ops!(@binary $t, Add add add_assign, Sub sub sub_assign,
+            Mul mul mul_assign, Div div div_assign, Rem rem rem_assign);)*
+    };
+    (@binary $t:ty, $($tr:ident $m:ident $assign:ident),*) => {
+        $(impl $tr<$t> for BigInt {
+            type Output = BigInt;
+            fn $m(mut self, n: $t) -> BigInt {
+                self.$assign(n);
+                self
+            }
+        }
+
+        impl $tr<$t> for &BigInt {
+            type Output = BigInt;
+            fn $m(self, n: $t) -> BigInt {
+                self.clone().$m(n)
+            }
+        }
+
+        impl $tr<BigInt> for $t {
+            type Output = BigInt;
+            fn $m(self, other: BigInt) -> BigInt {
+                BigInt::from(self).$m(&other)
+            }
+        }
+
+        impl<'a> $tr<&'a BigInt> for $t {
+            type Output = BigInt;
+            fn $m(self, other: &'a BigInt) -> BigInt {
+                BigInt::from(self).$m(other)
+            }
+        })*
+    };
+}
+
+impl_primitive_ops!(u32, u64, usize, i32, i64);
+
+// (|n|, n < 0) for the primitive operand types, which all fit in an i128.
+fn split_sign(n: i128) -> (u64, bool) {
+    (n.unsigned_abs() as u64, n < 0)
+}
+
+fn u64_limbs(n: u64) -> [u32; 3] {
+    [(n % BASE) as u32, (n / BASE % BASE) as u32, (n / BASE / BASE) as u32]
+}
+
+// Bitwise operators, with infinite two's-complement semantics for negatives.
+// &, | and ^ convert both operands to binary and the result back.
+impl BitAnd for BigInt {
+    type Output = BigInt;
+    fn bitand(self, other: BigInt) -> BigInt {
+        self.bitwise(&other, |a, b| a & b)
+    }
+}
+
+impl<'a> BitAnd for &'a BigInt {
+    type Output = BigInt;
+    fn bitand(self, other: &'a BigInt) -> BigInt {
+        self.bitwise(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for BigInt {
+    type Output = BigInt;
+    fn bitor(self, other: BigInt) -> BigInt {
+        self.bitwise(&other, |a, b| a | b)
+    }
+}
+
+impl<'a> BitOr for &'a BigInt {
+    type Output = BigInt;
+    fn bitor(self, other: &'a BigInt) -> BigInt {
+        self.bitwise(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for BigInt {
+    type Output = BigInt;
+    fn bitxor(self, other: BigInt) -> BigInt {
+        self.bitwise(&other, |a, b| a ^ b)
+    }
+}
+
+impl<'a> BitXor for &'a BigInt {
+    type Output = BigInt;
+    fn bitxor(self, other: &'a BigInt) -> BigInt {
+        self.bitwise(other, |a, b| a ^ b)
+    }
+}
+
+impl Shl<usize> for BigInt {
+    type Output = BigInt;
+    fn shl(self, n: usize) -> BigInt {
+        BigInt::shl(&self, n)
+    }
+}
+
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+    fn shl(self, n: usize) -> BigInt {
+        BigInt::shl(self, n)
+    }
+}
+
+impl Shr<usize> for BigInt {
+    type Output = BigInt;
+    fn shr(self, n: usize) -> BigInt {
+        BigInt::shr(&self, n)
+    }
+}
+
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+    fn shr(self, n: usize) -> BigInt {
+        BigInt::shr(self, n)
+    }
+}
+
+// !x == -x - 1, a single subtraction with no conversion.
+impl Not for BigInt {
+    type Output = BigInt;
+    fn not(self) -> BigInt {
+        !&self
+    }
+}
+
+impl Not for &BigInt {
+    type Output = BigInt;
+    fn not(self) -> BigInt {
+        &self.with_sign(!self.is_negative) - &BigInt::one()
+    }
+}
+
+// Conversions from and to the primitive integers
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for BigInt {
+            fn from(n: $t) -> BigInt {
+                BigInt::from_u128(n as u128)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for BigInt {
+            fn from(n: $t) -> BigInt {
+                BigInt::from_u128(n.unsigned_abs() as u128).with_sign(n < 0)
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_try_from_bigint {
+    ($($t:ty),*) => {
+        $(impl<'a> TryFrom<&'a BigInt> for $t {
+            type Error = TryFromBigIntError;
+            fn try_from(n: &'a BigInt) -> Result<$t, TryFromBigIntError> {
+                let magnitude = n.magnitude_u128().ok_or(TryFromBigIntError)?;
+                if n.is_negative {
+                    // i128::MIN has no positive counterpart, so go through its magnitude.
+                    if magnitude > i128::MIN.unsigned_abs() {
+                        return Err(TryFromBigIntError);
+                    }
+                    let value = (magnitude as i128).wrapping_neg();
+                    <$t>::try_from(value).map_err(|_| TryFromBigIntError)
+                } else {
+                    <$t>::try_from(magnitude).map_err(|_| TryFromBigIntError)
+                }
+            }
+        })*
+    };
+}
+
+impl_try_from_bigint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Comparison
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative, other.is_negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            _ => {
+                if self.digits.len() != other.digits.len() {
+                    if !self.is_negative {
+                        self.digits.len().cmp(&other.digits.len())
+                    } else {
+                        other.digits.len().cmp(&self.digits.len())
+                    }
+                } else {
+                    for (a, b) in self.digits.iter().rev().zip(other.digits.iter().rev()) {
+                        match a.cmp(b) {
+                            Ordering::Equal => continue,
+                            ord => {
+                                if !self.is_negative {
+                                    return ord;
+                                } else {
+                                    return ord.reverse();
+                                }
+                            }
+                        }
+                    }
+                    Ordering::Equal
+                }
+            }
+        }
+    }
+}
+
+// Display, honouring width, fill, alignment and `+` like the primitive integers
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.is_negative, "", &self.magnitude_str_radix(10))
+    }
+}
+
+// Radix formatting. Negatives print as sign and magnitude, "-0xff", not in
+// two's complement like the primitive integers.
+impl fmt::LowerHex for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.is_negative, "0x", &self.magnitude_str_radix(16))
+    }
+}
+
+impl fmt::UpperHex for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.magnitude_str_radix(16).to_ascii_uppercase();
+        f.pad_integral(!self.is_negative, "0x", &digits)
+    }
+}
+
+impl fmt::Binary for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.is_negative, "0b", &self.magnitude_str_radix(2))
+    }
+}
+
+impl fmt::Octal for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.is_negative, "0o", &self.magnitude_str_radix(8))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BigInt::from_str_radix(s, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    const RANDOM_CASES: usize = 20_000;
+
+    // Values around every limb boundary of i128, with both signs.
+    fn edge_values() -> Vec<i128> {
+        let mut magnitudes = vec![0, 1, 2, i64::MAX as i128, u64::MAX as i128, usize::MAX as i128];
+        for limbs in 0..5 {
+            let power = 10i128.pow(9 * limbs);
+            magnitudes.extend([power - 1, power, power + 1, 2 * power - 1]);
+        }
+        for shift in [31, 32, 63, 64, 126] {
+            magnitudes.extend([(1i128 << shift) - 1, 1i128 << shift, (1i128 << shift) + 1]);
+        }
+        magnitudes.extend([i128::MAX - 1, i128::MAX]);
+
+        let mut values: Vec<i128> = magnitudes.iter().flat_map(|&n| [n, -n]).collect();
+        values.push(i128::MIN);
+        values.push(i128::MIN + 1);
+        values.sort();
+        values.dedup();
+        values
+    }
+
+    // Random value of a random bit length below `bits`, so small and large
+    // magnitudes are equally common, with an occasional nudge onto a limb boundary.
+    fn random_value(rng: &mut Rng, bits: u32) -> i128 {
+        let len = rng.below(bits as u64 + 1) as u32;
+        let raw = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+        let mut n = if len == 0 { 0 } else { (raw >> (128 - len)) as i128 };
+        if rng.below(4) == 0 {
+            let power = 10i128.pow(9 * rng.below(bits as u64 / 30 + 1) as u32);
+            n = (n % 8 - 4).saturating_add(power.min(i128::MAX >> (128 - bits)));
+        }
+        if rng.below(2) == 0 {
+            -n
+        } else {
+            n
+        }
+    }
+
+    fn big(n: i128) -> BigInt {
+        BigInt::from(n)
+    }
+
+    fn check_arithmetic(a: i128, b: i128) {
+        let (x, y) = (big(a), big(b));
+        if let Some(sum) = a.checked_add(b) {
+            assert_eq!(BigInt::add(&x, &y), big(sum), "{} + {}", a, b);
+        }
+        if let Some(difference) = a.checked_sub(b) {
+            assert_eq!(BigInt::sub(&x, &y), big(difference), "{} - {}", a, b);
+        }
+        if let Some(product) = a.checked_mul(b) {
+            assert_eq!(BigInt::mul(&x, &y), big(product), "{} * {}", a, b);
+        }
+        if let (Some(quotient), Some(remainder)) = (a.checked_div(b), a.checked_rem(b)) {
+            assert_eq!(x.div_rem(&y), (big(quotient), big(remainder)), "{} / {}", a, b);
+            assert_eq!(x.modulo(&y), big(remainder), "{} % {}", a, b);
+        }
+        assert_eq!(x.cmp(&y), a.cmp(&b), "{} <=> {}", a, b);
+        assert_eq!(x == y, a == b, "{} == {}", a, b);
+    }
+
+    fn check_conversions(a: i128) {
+        let x = big(a);
+        let text = x.to_string();
+        assert_eq!(text, a.to_string());
+        assert_eq!(BigInt::from_str(&text), Ok(x.clone()));
+        assert_eq!(text.parse::<BigInt>(), Ok(x.clone()));
+        assert_eq!(x.to_usize(), usize::try_from(a).ok(), "to_usize({})", a);
+        assert_eq!(i128::try_from(&x), Ok(a));
+        assert_eq!(x.is_even(), a % 2 == 0, "is_even({})", a);
+
+        // Leading zeros must not leak into the limbs.
+        let padded = match text.strip_prefix('-') {
+            Some(digits) => format!("-0000000000{}", digits),
+            None => format!("0000000000{}", text),
+        };
+        assert_eq!(BigInt::from_str(&padded), Ok(x));
+    }
+
+    #[test]
+    fn edge_pairs_match_i128() {
+        let values = edge_values();
+        for &a in &values {
+            check_conversions(a);
+            for &b in &values {
+                check_arithmetic(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn random_pairs_match_i128() {
+        let mut rng = Rng::new(0xb16_1e7);
+        for _ in 0..RANDOM_CASES {
+            let a = random_value(&mut rng, 127);
+            let b = random_value(&mut rng, 127);
+            check_arithmetic(a, b);
+            check_conversions(a);
+
+            // Half-width operands so products stay in range.
+            check_arithmetic(random_value(&mut rng, 63), random_value(&mut rng, 63));
+            check_arithmetic(a, random_value(&mut rng, 40));
+        }
+    }
+
+    #[test]
+    fn zero_is_never_negative() {
+        let one = BigInt::one();
+        assert!(!BigInt::sub(&one, &one).is_negative());
+        assert!(!BigInt::mul(&big(-5), &BigInt::zero()).is_negative());
+        assert!(!big(-3).modulo(&big(3)).is_negative());
+        assert!(!big(-2).div_rem(&big(3)).0.is_negative());
+        assert_eq!(BigInt::from_str("-0"), Ok(BigInt::zero()));
+        assert_eq!(BigInt::from_str("-0").unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for text in ["", "-", "+", "12a3", "1 2", "--1", "1-"] {
+            assert!(BigInt::from_str(text).is_err(), "{:?}", text);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero_panics() {
+        big(1).div_rem(&BigInt::zero());
+    }
+
+    // `len` random limbs, about a quarter of them zero so whole runs vanish.
+    fn random_limbs(rng: &mut Rng, len: usize) -> Vec<u32> {
+        (0..len)
+            .map(|_| if rng.below(4) == 0 { 0 } else { rng.below(BASE) as u32 })
+            .collect()
+    }
+
+    fn check_karatsuba(a: &[u32], b: &[u32]) {
+        let mut expected = vec![0; a.len() + b.len()];
+        schoolbook_acc(&mut expected, a, b);
+        let mut actual = vec![0; a.len() + b.len()];
+        karatsuba_acc(&mut actual, a, b);
+        assert_eq!(actual, expected, "{} x {} limbs", a.len(), b.len());
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        let mut rng = Rng::new(0x7a4a_75ba);
+        for _ in 0..40 {
+            let n = KARATSUBA_THRESHOLD + rng.below(469) as usize;
+            let m = KARATSUBA_THRESHOLD + rng.below(n as u64 - 31) as usize;
+            check_karatsuba(&random_limbs(&mut rng, n), &random_limbs(&mut rng, m));
+        }
+    }
+
+    #[test]
+    fn karatsuba_handles_unbalanced_operands() {
+        let mut rng = Rng::new(0x700_300);
+        for (n, m) in [(700, 300), (500, 32), (333, 166), (64, 33)] {
+            check_karatsuba(&random_limbs(&mut rng, n), &random_limbs(&mut rng, m));
+        }
+    }
+
+    #[test]
+    fn karatsuba_handles_zero_limbs() {
+        let mut rng = Rng::new(0x2e60);
+        let (a, b) = (random_limbs(&mut rng, 200), random_limbs(&mut rng, 150));
+
+        // Zero low halves, zero high halves, zero tops and all-zero operands.
+        let mut low = a.clone();
+        low[..100].fill(0);
+        let mut high = b.clone();
+        high[75..].fill(0);
+        let mut top = a.clone();
+        top[190..].fill(0);
+        check_karatsuba(&low, &b);
+        check_karatsuba(&a, &high);
+        check_karatsuba(&low, &high);
+        check_karatsuba(&top, &high);
+        check_karatsuba(&a, &[0; 100]);
+        check_karatsuba(&[BASE as u32 - 1; 120], &[BASE as u32 - 1; 120]);
+    }
+
+    // Limbs drawn mostly from the extremes, where quotient estimates go wrong.
+    fn awkward_limbs(rng: &mut Rng, len: usize) -> Vec<u32> {
+        const LIMBS: [u32; 6] = [0, 1, 499_999_999, 500_000_000, 999_999_998, 999_999_999];
+        (0..len)
+            .map(|_| match rng.below(3) {
+                0 => rng.below(BASE) as u32,
+                _ => LIMBS[rng.below(LIMBS.len() as u64) as usize],
+            })
+            .collect()
+    }
+
+    fn check_knuth_d(u: &[u32], v: &[u32]) {
+        let (u, v) = (trimmed(u), trimmed(v));
+        if v.len() < 2 || cmp_digits(u, v) == Ordering::Less {
+            return;
+        }
+
+        let (q, r) = knuth_d(u, v);
+        assert_eq!(cmp_digits(&r, v), Ordering::Less, "remainder too large");
+        let mut product = mul_digits(&q, v);
+        product.resize(product.len().max(u.len()) + 1, 0);
+        add_assign_digits(&mut product, trimmed(&r));
+        assert_eq!(trimmed(&product), u, "q * v + r != u");
+    }
+
+    #[test]
+    fn knuth_d_round_trips() {
+        let mut rng = Rng::new(0xd1_7d);
+        for _ in 0..2000 {
+            let n = 2 + rng.below(8) as usize;
+            let m = n + rng.below(8) as usize;
+            check_knuth_d(&awkward_limbs(&mut rng, m), &awkward_limbs(&mut rng, n));
+            check_knuth_d(&random_limbs(&mut rng, m), &random_limbs(&mut rng, n));
+        }
+    }
+
+    #[test]
+    fn knuth_d_handles_extreme_divisors() {
+        let mut rng = Rng::new(0x99_9999);
+        let max = BASE as u32 - 1;
+        for n in 2..12 {
+            // No scaling, and the largest scale that leaves the top limb below BASE / 2.
+            let nines = vec![max; n];
+            let mut half = random_limbs(&mut rng, n);
+            half[n - 1] = (BASE / 2) as u32 - 1;
+
+            for v in [&nines, &half] {
+                check_knuth_d(&[max; 24], v);
+                check_knuth_d(&random_limbs(&mut rng, 24), v);
+                check_knuth_d(&awkward_limbs(&mut rng, 24), v);
+                check_knuth_d(v, v);
+
+                // v * B^k - 1 forces the largest quotient limbs.
+                let mut u = vec![0; 4];
+                u.extend_from_slice(v);
+                sub_assign_digits(&mut u, &[1]);
+                check_knuth_d(&u, v);
+            }
+        }
+    }
+
+    #[test]
+    fn floored_and_euclidean_division_signs() {
+        // (a, b, div_floor, mod_floor, div_euclid, rem_euclid)
+        let cases = [
+            (7, 3, 2, 1, 2, 1),
+            (-7, 3, -3, 2, -3, 2),
+            (7, -3, -3, -2, -2, 1),
+            (-7, -3, 2, -1, 3, 2),
+            (6, -3, -2, 0, -2, 0),
+            (-6, -3, 2, 0, 2, 0),
+        ];
+        for (a, b, q_floor, r_floor, q_euclid, r_euclid) in cases {
+            let (x, y) = (big(a), big(b));
+            assert_eq!(x.div_floor(&y), big(q_floor), "{} div_floor {}", a, b);
+            assert_eq!(x.mod_floor(&y), big(r_floor), "{} mod_floor {}", a, b);
+            assert_eq!(x.div_mod_floor(&y), (big(q_floor), big(r_floor)));
+            assert_eq!(x.div_euclid(&y), big(q_euclid), "{} div_euclid {}", a, b);
+            assert_eq!(x.rem_euclid(&y), big(r_euclid), "{} rem_euclid {}", a, b);
+        }
+
+        // Multi-limb operands in every sign combination, against i128.
+        let (a, b) = (123_456_789_012_345_678_901_234_567i128, 987_654_321_987i128);
+        for (a, b) in [(a, b), (-a, b), (a, -b), (-a, -b)] {
+            let (x, y) = (big(a), big(b));
+            assert_eq!(x.div_euclid(&y), big(a.div_euclid(b)), "{} div_euclid {}", a, b);
+            assert_eq!(x.rem_euclid(&y), big(a.rem_euclid(b)), "{} rem_euclid {}", a, b);
+            let q_floor = a.div_euclid(b) - (b < 0 && a.rem_euclid(b) != 0) as i128;
+            assert_eq!(x.div_floor(&y), big(q_floor), "{} div_floor {}", a, b);
+            assert_eq!(x.mod_floor(&y), big(a - q_floor * b), "{} mod_floor {}", a, b);
+        }
+    }
+
+    #[test]
+    fn pow_and_modpow() {
+        assert_eq!(big(0).pow(0), big(1));
+        assert_eq!(big(-3).pow(5), big(-243));
+        assert_eq!(big(-3).pow(4), big(81));
+        assert_eq!(big(10).pow(30), BigInt::from_str(&format!("1{}", "0".repeat(30))).unwrap());
+
+        // Negative bases reduce into 0..|modulus|, like Python's pow(b, e, m).
+        assert_eq!(big(-2).modpow(&big(3), &big(5)), big(2));
+        assert_eq!(big(-2).modpow(&big(2), &big(5)), big(4));
+        assert_eq!(big(-7).modpow(&big(0), &big(13)), big(1));
+        assert_eq!(big(3).modpow(&big(0), &big(1)), big(0));
+        assert_eq!(big(4).modpow(&big(13), &big(-497)), big(445));
+
+        let p = big(1_000_000_007);
+        let base = big(-123_456_789_123);
+        let exp = BigInt::from_str("123456789012345678901234567890").unwrap();
+        let expected = base.pow(5).rem_euclid(&p);
+        assert_eq!(base.modpow(&big(5), &p), expected);
+        // Fermat: b^(p - 1) == 1, so only exp mod (p - 1) matters.
+        let reduced = exp.rem_euclid(&(&p - &BigInt::one()));
+        assert_eq!(base.modpow(&exp, &p), base.modpow(&reduced, &p));
+    }
+
+    #[test]
+    fn mod_inverse_requires_coprime_inputs() {
+        assert_eq!(big(3).mod_inverse(&big(11)), Some(big(4)));
+        assert_eq!(big(-3).mod_inverse(&big(11)), Some(big(7)));
+        assert_eq!(big(3).mod_inverse(&big(-11)), Some(big(4)));
+        assert_eq!(big(6).mod_inverse(&big(9)), None);
+        assert_eq!(big(0).mod_inverse(&big(7)), None);
+        assert_eq!(big(10).mod_inverse(&big(0)), None);
+        assert_eq!(big(-4).mod_inverse(&big(-8)), None);
+
+        let modulus = BigInt::from_str("170141183460469231731687303715884105727").unwrap();
+        let value = BigInt::from_str("-98765432109876543210").unwrap();
+        let inverse = value.mod_inverse(&modulus).unwrap();
+        assert!(!inverse.is_negative() && inverse < modulus);
+        assert!((&value * &inverse).rem_euclid(&modulus).is_one());
+    }
+
+    fn check_bitwise(a: i128, b: i128) {
+        let (x, y) = (big(a), big(b));
+        assert_eq!(&x & &y, big(a & b), "{} & {}", a, b);
+        assert_eq!(&x | &y, big(a | b), "{} | {}", a, b);
+        assert_eq!(&x ^ &y, big(a ^ b), "{} ^ {}", a, b);
+    }
+
+    fn check_bits(a: i128) {
+        let x = big(a);
+        assert_eq!(!&x, big(!a), "!{}", a);
+        assert_eq!(x.bit_len(), 128 - a.unsigned_abs().leading_zeros() as usize, "{}", a);
+        assert_eq!(x.count_ones(), a.unsigned_abs().count_ones() as usize, "{}", a);
+        for n in [0, 1, 31, 32, 33, 63, 64, 100, 127, 128, 200] {
+            assert_eq!(x.bit(n), a >> n.min(127) & 1 == 1, "bit {} of {}", n, a);
+            assert_eq!(&x >> n, big(a >> n.min(127)), "{} >> {}", a, n);
+        }
+        for n in [0, 1, 31, 32, 33] {
+            if let Some(shifted) = a.checked_mul(1 << n) {
+                assert_eq!(&x << n, big(shifted), "{} << {}", a, n);
+            }
+        }
+    }
+
+    #[test]
+    fn bitwise_matches_i128_twos_complement() {
+        let values = edge_values();
+        for &a in &values {
+            check_bits(a);
+            for &b in &values {
+                check_bitwise(a, b);
+            }
+        }
+
+        let mut rng = Rng::new(0xb175);
+        for _ in 0..2000 {
+            let a = random_value(&mut rng, 127);
+            check_bits(a);
+            check_bitwise(a, random_value(&mut rng, 127));
+        }
+
+        // -1 has every bit set, -2^k all but the low k.
+        assert_eq!(&big(-1) & &big(12345), big(12345));
+        assert_eq!(&big(-8) | &big(5), big(-3));
+        assert_eq!(&big(-8) ^ &big(-1), big(7));
+        assert_eq!(&big(-5) >> 1, big(-3));
+    }
+
+    #[test]
+    fn bit_agrees_with_shift_on_large_values() {
+        let mut rng = Rng::new(0xb17);
+        let digits: String = (0..1500).map(|_| char::from(b'0' + rng.below(10) as u8)).collect();
+        let x = BigInt::from_str(&format!("7{}", digits)).unwrap();
+        let len = x.bit_len();
+        for value in [x.clone(), -&x] {
+            for n in [0, 1, 31, 1000, 2047, 2048, 2049, 3000, len - 1, len, len + 1, 10 * len] {
+                assert_eq!(value.bit(n), !(&value >> n).is_even(), "bit {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn parses_radix_prefixes_and_separators() {
+        let parse = |s: &str, radix| BigInt::from_str_radix(s, radix);
+        assert_eq!(parse("-0xdead_beef", 16), Ok(big(-0xdead_beef)));
+        assert_eq!(parse("0XFF", 16), Ok(big(255)));
+        assert_eq!(parse("ff", 16), Ok(big(255)));
+        assert_eq!(parse("0b1010_1010", 2), Ok(big(0b1010_1010)));
+        assert_eq!(parse("0o777", 8), Ok(big(0o777)));
+        assert_eq!(parse("1_000_000", 10), Ok(big(1_000_000)));
+        assert_eq!(parse("zz", 36), Ok(big(36 * 36 - 1)));
+        assert_eq!(parse("-0", 7), Ok(BigInt::zero()));
+
+        // A prefix only belongs to its own radix, and separators only go between digits.
+        let invalid = |position, char| Err(ParseBigIntError::InvalidDigit { position, char });
+        assert_eq!(parse("0x10", 10), invalid(1, 'x'));
+        assert_eq!(parse("0b10", 8), invalid(1, 'b'));
+        assert_eq!(parse("_1", 10), invalid(0, '_'));
+        assert_eq!(parse("1__0", 10), invalid(2, '_'));
+        assert_eq!(parse("10_", 10), invalid(2, '_'));
+        assert_eq!(parse("0x_ff", 16), invalid(2, '_'));
+        assert_eq!(parse("0x", 16), Err(ParseBigIntError::Empty));
+        assert_eq!(parse("12", 2), invalid(1, '2'));
+        assert_eq!(parse("1", 37), Err(ParseBigIntError::InvalidRadix(37)));
+    }
+
+    #[test]
+    fn formats_in_other_radices() {
+        let x = big(0xdead_beef);
+        assert_eq!(format!("{:x}", x), "deadbeef");
+        assert_eq!(format!("{:#x}", x), "0xdeadbeef");
+        assert_eq!(format!("{:#x}", -&x), "-0xdeadbeef");
+        assert_eq!(format!("{:#010X}", big(255)), "0x000000FF");
+        assert_eq!(format!("{:#010X}", big(-255)), "-0x00000FF");
+        assert_eq!(format!("{:b}", big(10)), "1010");
+        assert_eq!(format!("{:#b}", big(-10)), "-0b1010");
+        assert_eq!(format!("{:o}", big(8)), "10");
+        assert_eq!(format!("{:x}", BigInt::zero()), "0");
+
+        let huge = big(-1).shl(200);
+        assert_eq!(format!("{:x}", huge), format!("-1{}", "0".repeat(50)));
+        assert_eq!(BigInt::from_str_radix(&huge.to_str_radix(36), 36), Ok(huge));
+    }
+
+    #[test]
+    fn display_honours_format_flags() {
+        assert_eq!(format!("[{:>6}]", BigInt::from(5)), "[     5]");
+        assert_eq!(format!("[{:<6}]", BigInt::from(-5)), "[-5    ]");
+        assert_eq!(format!("[{:*^7}]", BigInt::from(-42)), "[**-42**]");
+        assert_eq!(format!("{:+}", BigInt::from(5)), "+5");
+        assert_eq!(format!("{:+}", BigInt::from(-5)), "-5");
+        assert_eq!(format!("{:+}", BigInt::zero()), "+0");
+        assert_eq!(format!("{:06}", BigInt::from(-42)), "-00042");
+
+        let x = BigInt::from_str("-1000000000000000000000").unwrap();
+        assert_eq!(format!("{:>25}", x), format!("{:>25}", "-1000000000000000000000"));
+        assert_eq!(format!("{}", x), "-1000000000000000000000");
+    }
+
+    #[test]
+    fn parse_options_relax_signs_and_whitespace() {
+        let lenient = ParseOptions {
+            allow_plus: true,
+            trim_whitespace: true,
+        };
+        let plus_only = ParseOptions {
+            allow_plus: true,
+            ..ParseOptions::default()
+        };
+        let trim_only = ParseOptions {
+            trim_whitespace: true,
+            ..ParseOptions::default()
+        };
+
+        assert_eq!(BigInt::from_str_with("  +42\n", 10, lenient), Ok(big(42)));
+        assert_eq!(BigInt::from_str_with("\t-0x2a ", 16, lenient), Ok(big(-42)));
+        assert_eq!(BigInt::from_str_with("+42", 10, plus_only), Ok(big(42)));
+        assert_eq!(BigInt::from_str_with(" 42 ", 10, trim_only), Ok(big(42)));
+
+        // Each option only relaxes its own rule, and the default relaxes neither.
+        let invalid = |position, char| Err(ParseBigIntError::InvalidDigit { position, char });
+        assert_eq!(BigInt::from_str_with(" 42", 10, plus_only), invalid(0, ' '));
+        assert_eq!(BigInt::from_str_with(" +42", 10, trim_only), invalid(1, '+'));
+        assert_eq!(BigInt::from_str("+42"), invalid(0, '+'));
+        assert_eq!(BigInt::from_str("42 "), invalid(2, ' '));
+        assert_eq!(BigInt::from_str_with("+-1", 10, lenient), invalid(1, '-'));
+        assert_eq!(BigInt::from_str_with(" + ", 10, lenient), Err(ParseBigIntError::Empty));
+        assert_eq!(BigInt::from_str_with("   ", 10, lenient), Err(ParseBigIntError::Empty));
+        assert_eq!(BigInt::from_str_with("4 2", 10, lenient), invalid(1, ' '));
+    }
+
+    #[test]
+    fn primitive_conversions_at_the_limits() {
+        assert_eq!(i8::try_from(&big(-128)), Ok(i8::MIN));
+        assert_eq!(i8::try_from(&big(-129)), Err(TryFromBigIntError));
+        assert_eq!(i8::try_from(&big(127)), Ok(i8::MAX));
+        assert_eq!(i8::try_from(&big(128)), Err(TryFromBigIntError));
+        assert_eq!(u8::try_from(&big(-1)), Err(TryFromBigIntError));
+        assert_eq!(u32::try_from(&big(u32::MAX as i128 + 1)), Err(TryFromBigIntError));
+
+        let u128_max = BigInt::from(u128::MAX);
+        assert_eq!(u128_max.to_string(), u128::MAX.to_string());
+        assert_eq!(u128::try_from(&u128_max), Ok(u128::MAX));
+        assert_eq!(u128::try_from(&(&u128_max + &BigInt::one())), Err(TryFromBigIntError));
+        assert_eq!(i128::try_from(&u128_max), Err(TryFromBigIntError));
+
+        let i128_min = BigInt::from(i128::MIN);
+        assert_eq!(i128::try_from(&i128_min), Ok(i128::MIN));
+        assert_eq!(i128::try_from(&(&i128_min - &BigInt::one())), Err(TryFromBigIntError));
+        assert_eq!(i64::try_from(&BigInt::from(i64::MIN)), Ok(i64::MIN));
+        assert_eq!(isize::try_from(&BigInt::from(isize::MIN)), Ok(isize::MIN));
+    }
+
+    #[test]
+    fn f64_conversions() {
+        assert_eq!(BigInt::from_f64(1.9), Some(big(1)));
+        assert_eq!(BigInt::from_f64(-2.7), Some(big(-2)));
+        assert_eq!(BigInt::from_f64(-0.0), Some(BigInt::zero()));
+        assert_eq!(BigInt::from_f64(f64::MIN_POSITIVE), Some(BigInt::zero()));
+        assert_eq!(BigInt::from_f64(1e20), Some(big(100_000_000_000_000_000_000)));
+        assert_eq!(BigInt::from_f64(-(2f64.powi(100))), Some(big(-1).shl(100)));
+        assert_eq!(BigInt::from_f64(f64::NAN), None);
+        assert_eq!(BigInt::from_f64(f64::INFINITY), None);
+        assert_eq!(BigInt::from_f64(f64::NEG_INFINITY), None);
+
+        let max = BigInt::from_f64(f64::MAX).unwrap();
+        assert_eq!(max.to_f64(), f64::MAX);
+        assert_eq!(max.bit_len(), 1024);
+        assert_eq!(big(-1).shl(1024).to_f64(), f64::NEG_INFINITY);
+
+        // 2^53 + 1 is a tie between 2^53 and 2^53 + 2 and rounds to even.
+        assert_eq!(big((1 << 53) + 1).to_f64(), 2f64.powi(53));
+        assert_eq!(big((1 << 53) + 3).to_f64(), 2f64.powi(53) + 4.0);
+        assert_eq!(big(-12345).to_f64(), -12345.0);
+        for f in [0.0, 1.0, -1.0, 123456789.0, 1e300, -4.5e18] {
+            assert_eq!(BigInt::from_f64(f).unwrap().to_f64(), f.trunc(), "{}", f);
+        }
+    }
+
+    fn check_root(x: &BigInt, n: u32) {
+        let root = x.nth_root(n).unwrap();
+        let next = &root + &BigInt::one();
+        assert!(root.pow(n) <= *x && next.pow(n) > *x, "{}th root of {}", n, x);
+    }
+
+    #[test]
+    fn nth_root_floors_around_perfect_powers() {
+        let mut rng = Rng::new(0x2007);
+        let large = BigInt::from_str(&"987654321".repeat(12)).unwrap();
+        for k in [big(2), big(3), big(10), big(999_999_999), big(1) << 70, large] {
+            for n in [2, 3, 5, 7, 64, 100] {
+                let power = k.pow(n);
+                assert_eq!(power.nth_root(n), Ok(k.clone()), "{}^{}", k, n);
+                let below = &power - &BigInt::one();
+                assert_eq!(below.nth_root(n), Ok(&k - &BigInt::one()), "{}^{} - 1", k, n);
+                let above = &power + &BigInt::one();
+                assert_eq!(above.nth_root(n), Ok(k.clone()), "{}^{} + 1", k, n);
+            }
+        }
+
+        for _ in 0..500 {
+            let x = big(random_value(&mut rng, 127).abs());
+            check_root(&x, 1 + rng.below(8) as u32);
+        }
+        assert!(big(10).pow(1000).is_perfect_square());
+        assert!(!(&big(10).pow(1000) - &BigInt::one()).is_perfect_square());
+    }
+
+    #[test]
+    fn nth_root_small_and_negative_inputs() {
+        for n in [1, 2, 3, 100] {
+            assert_eq!(BigInt::zero().nth_root(n), Ok(BigInt::zero()));
+            assert_eq!(BigInt::one().nth_root(n), Ok(BigInt::one()));
+            assert_eq!(big(-1).nth_root(n), Err(NegativeRootError));
+        }
+        assert_eq!(big(-4).sqrt(), Err(NegativeRootError));
+        assert!(!big(-4).is_perfect_square());
+
+        // Once n reaches the bit length the root is 1.
+        assert_eq!(big(255).nth_root(8), Ok(BigInt::one()));
+        assert_eq!(big(256).nth_root(8), Ok(big(2)));
+        assert_eq!(big(256).nth_root(9), Ok(BigInt::one()));
+        assert_eq!(big(1).shl(1000).nth_root(u32::MAX), Ok(BigInt::one()));
+    }
+
+    #[test]
+    fn primitive_operators_at_the_limits() {
+        let values = [big(0), big(7), big(-7), big(i128::MAX), big(i128::MIN), big(1) << 200];
+        for x in &values {
+            for n in [i64::MIN, i64::MIN + 1, -1, 1, i64::MAX] {
+                let y = BigInt::from(n);
+                assert_eq!(x + n, x + &y, "{} + {}", x, n);
+                assert_eq!(x - n, x - &y, "{} - {}", x, n);
+                assert_eq!(x * n, x * &y, "{} * {}", x, n);
+                assert_eq!(x / n, x / &y, "{} / {}", x, n);
+                assert_eq!(x % n, x % &y, "{} % {}", x, n);
+                assert_eq!(n - x, &y - x, "{} - {}", n, x);
+                if !x.is_zero() {
+                    assert_eq!(n / x.clone(), &y / x, "{} / {}", n, x);
+                }
+            }
+            for n in [u64::MAX, u64::MAX - 1, 1 << 32, u32::MAX as u64] {
+                let y = BigInt::from(n);
+                assert_eq!(x + n, x + &y, "{} + {}", x, n);
+                assert_eq!(x - n, x - &y, "{} - {}", x, n);
+                assert_eq!(x * n, x * &y, "{} * {}", x, n);
+                assert_eq!(x / n, x / &y, "{} / {}", x, n);
+                assert_eq!(x % n, x % &y, "{} % {}", x, n);
+                assert_eq!(n * x, &y * x, "{} * {}", n, x);
+            }
+        }
+
+        let mut x = big(i64::MIN as i128);
+        x -= 1i64;
+        x *= -1i32;
+        assert_eq!(x, big(i64::MAX as i128 + 2));
+        x += u64::MAX;
+        x /= 2u32;
+        assert_eq!(x, big((i64::MAX as i128 + 2 + u64::MAX as i128) / 2));
+        x %= 1_000_000_007usize;
+        assert_eq!(x, big((i64::MAX as i128 + 2 + u64::MAX as i128) / 2 % 1_000_000_007));
+    }
+
+    #[test]
+    fn sums_and_products() {
+        // Every third value negated, an odd number of them in all.
+        let signed: Vec<i128> = (1..=23).map(|n| if n % 3 == 0 { -n } else { n }).collect();
+        let values: Vec<BigInt> = signed.iter().map(|&n| big(n)).collect();
+        let sum: BigInt = values.iter().sum();
+        assert_eq!(sum, big(signed.iter().sum()));
+        let product: BigInt = values.iter().product();
+        assert_eq!(product, big(signed.iter().product()));
+        assert!(product.is_negative());
+        assert_eq!(values.clone().into_iter().sum::<BigInt>(), sum);
+        assert_eq!(values.into_iter().product::<BigInt>(), product);
+
+        let empty: Vec<BigInt> = Vec::new();
+        assert_eq!(empty.iter().sum::<BigInt>(), BigInt::zero());
+        assert_eq!(empty.iter().product::<BigInt>(), BigInt::one());
+        let large = [big(u64::MAX as i128), big(u64::MAX as i128), big(-1)];
+        assert_eq!(large.iter().product::<BigInt>(), -(big(u64::MAX as i128).pow(2)));
+    }
+
+    fn random_big(rng: &mut Rng, digits: usize) -> BigInt {
+        let mut s = String::from("9");
+        s.extend((1..digits).map(|_| char::from(b'0' + rng.below(10) as u8)));
+        BigInt::from_str(&s).unwrap()
+    }
+
+    #[test]
+    fn newton_division_matches_knuth() {
+        // 10k-digit divisor and quotient, both past NEWTON_THRESHOLD limbs.
+        let mut rng = Rng::new(0x10_000);
+        let v = random_big(&mut rng, 10_000);
+        let u = &(&v * &random_big(&mut rng, 10_000)) + &random_big(&mut rng, 9_000);
+        assert!(v.digits.len() >= NEWTON_THRESHOLD);
+        assert!(u.digits.len() - v.digits.len() >= NEWTON_THRESHOLD);
+
+        let (q, r) = u.div_rem(&v);
+        assert_eq!(&(&q * &v) + &r, u);
+        assert!(!r.is_negative() && r < v);
+        let (knuth_q, knuth_r) = knuth_d(&u.digits, &v.digits);
+        assert_eq!(q, BigInt::from_parts(knuth_q, false));
+        assert_eq!(r, BigInt::from_parts(knuth_r, false));
+
+        // A remainder of v - 1 is where a low quotient estimate shows.
+        let u = &(&(&v * &v) + &v) - &BigInt::one();
+        assert_eq!(u.div_rem(&v), (v.clone(), &v - &BigInt::one()));
+        assert_eq!((-&u).div_rem(&v), (-&v, &BigInt::one() - &v));
+    }
+
+    #[test]
+    fn radix_round_trips_through_the_power_tree() {
+        let mut rng = Rng::new(0x600);
+        for digits in [601, 2000, 6000] {
+            let x = random_big(&mut rng, digits);
+            assert!(x.digits.len() > CONVERSION_THRESHOLD);
+            for radix in [2, 7, 16, 36] {
+                let (_, chunk_base) = radix_chunk(radix);
+                let chunks = to_chunks(&x.digits, chunk_base as u64);
+                assert_eq!(chunks, to_chunks_small(&x.digits, chunk_base as u64));
+                assert_eq!(trimmed(&from_chunks(&chunks, chunk_base as u64)), x.digits);
+                assert_eq!(trimmed(&from_chunks_small(&chunks, chunk_base as u64)), x.digits);
+
+                let negative = -&x;
+                let text = negative.to_str_radix(radix);
+                assert_eq!(BigInt::from_str_radix(&text, radix), Ok(negative), "radix {}", radix);
+            }
+        }
+
+        // Powers of the radix leave runs of zero chunks in every half.
+        let power = big(16).pow(1000);
+        assert_eq!(format!("{:x}", power), format!("1{}", "0".repeat(1000)));
+        let power = &big(7).pow(1500) - &BigInt::one();
+        assert_eq!(power.to_str_radix(7), "6".repeat(1500));
+        assert_eq!(BigInt::from_str_radix(&"6".repeat(1500), 7), Ok(power));
+    }
+}
\ No newline at end of file
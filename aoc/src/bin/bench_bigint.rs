@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc::bigint::BigInt;
+use aoc::rng::Rng;
+
+// Usage: bench_bigint [--max-digits N] [--baseline FILE] [--save]
+//
+// Times each operation on random operands of 10, 100, ... digits and compares
+// against the baseline file, which `--save` overwrites with this run.
+
+const DEFAULT_BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/bench_bigint.baseline");
+const DEFAULT_MAX_DIGITS: usize = 1_000_000;
+const SEED: u64 = 0xbe7c;
+
+// Each measurement repeats the operation until this much time has passed.
+const MIN_TIME: Duration = Duration::from_millis(200);
+
+// Slower than baseline by more than this factor counts as a regression.
+const REGRESSION_FACTOR: f64 = 1.25;
+
+// Smaller sizes are dominated by call overhead, so they are left out of the fit.
+const FIT_MIN_DIGITS: usize = 1000;
+
+const OPS: [&str; 7] = ["add", "mul", "div_rem", "display", "from_str", "to_hex", "from_hex"];
+
+struct Options {
+    max_digits: usize,
+    baseline: String,
+    save: bool,
+}
+
+fn parse_options() -> Options {
+    let mut options = Options {
+        max_digits: DEFAULT_MAX_DIGITS,
+        baseline: DEFAULT_BASELINE.to_string(),
+        save: false,
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--max-digits" => {
+                let n = args.next().and_then(|n| n.parse().ok());
+                options.max_digits = n.unwrap_or_else(|| usage());
+            }
+            "--baseline" => options.baseline = args.next().unwrap_or_else(|| usage()),
+            "--save" => options.save = true,
+            _ => usage(),
+        }
+    }
+    options
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: bench_bigint [--max-digits N] [--baseline FILE] [--save]");
+    process::exit(2);
+}
+
+fn random_digits(rng: &mut Rng, digits: usize) -> String {
+    let mut s = String::with_capacity(digits);
+    s.push((b'1' + rng.below(9) as u8) as char);
+    for _ in 1..digits {
+        s.push((b'0' + rng.below(10) as u8) as char);
+    }
+    s
+}
+
+fn random_big(rng: &mut Rng, digits: usize) -> BigInt {
+    random_digits(rng, digits).parse::<BigInt>().unwrap()
+}
+
+// Average time of one call to `f`, in nanoseconds.
+fn time<R>(mut f: impl FnMut() -> R) -> f64 {
+    black_box(f());
+    let start = Instant::now();
+    let mut iterations = 0u32;
+    while start.elapsed() < MIN_TIME {
+        black_box(f());
+        iterations += 1;
+    }
+    start.elapsed().as_nanos() as f64 / iterations as f64
+}
+
+fn measure(op: &str, digits: usize, rng: &mut Rng) -> f64 {
+    let a = random_big(rng, digits);
+    let b = random_big(rng, digits);
+    match op {
+        "add" => time(|| &a + &b),
+        "mul" => time(|| &a * &b),
+        "div_rem" => {
+            // A 2n-digit dividend makes the quotient as long as the divisor.
+            let dividend = &a * &b;
+            time(|| dividend.div_rem(&b))
+        }
+        "display" => time(|| a.to_string()),
+        "from_str" => {
+            let s = a.to_string();
+            time(|| s.parse::<BigInt>().unwrap())
+        }
+        "to_hex" => time(|| a.to_str_radix(16)),
+        "from_hex" => {
+            let s = a.to_str_radix(16);
+            time(|| BigInt::from_str_radix(&s, 16).unwrap())
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Least-squares slope of log(ns) against log(digits).
+fn growth_exponent(points: &[(usize, f64)]) -> Option<f64> {
+    let points: Vec<(f64, f64)> = points.iter()
+        .filter(|&&(digits, _)| digits >= FIT_MIN_DIGITS)
+        .map(|&(digits, ns)| ((digits as f64).ln(), ns.ln()))
+        .collect();
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let variance: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    Some(covariance / variance)
+}
+
+// Baseline lines are "<op> <digits> <ns/op>".
+fn load_baseline(path: &str) -> HashMap<(String, usize), f64> {
+    let content = fs::read_to_string(path).unwrap_or_default();
+    content.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let op = parts.next()?.to_string();
+            let digits = parts.next()?.parse().ok()?;
+            let ns = parts.next()?.parse().ok()?;
+            Some(((op, digits), ns))
+        })
+        .collect()
+}
+
+fn main() {
+    let options = parse_options();
+    let baseline = load_baseline(&options.baseline);
+    let mut rng = Rng::new(SEED);
+
+    let sizes: Vec<usize> = (1..)
+        .map(|exp| 10usize.pow(exp))
+        .take_while(|&digits| digits <= options.max_digits)
+        .collect();
+
+    let mut saved = String::new();
+    let mut regressions = 0;
+
+    println!("{:<10}{:>10}{:>16}{:>16}{:>10}", "op", "digits", "ns/op", "baseline", "change");
+    for op in OPS {
+        let mut points = vec![];
+        for &digits in &sizes {
+            let ns = measure(op, digits, &mut rng);
+            points.push((digits, ns));
+            saved.push_str(&format!("{} {} {:.1}\n", op, digits, ns));
+
+            let (old, change) = match baseline.get(&(op.to_string(), digits)) {
+                Some(&old) => {
+                    let flag = if ns > old * REGRESSION_FACTOR {
+                        regressions += 1;
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    };
+                    (format!("{:.1}", old), format!("{:+.1}%{}", (ns / old - 1.0) * 100.0, flag))
+                }
+                None => ("-".to_string(), "-".to_string()),
+            };
+            println!("{:<10}{:>10}{:>16.1}{:>16}{:>10}", op, digits, ns, old, change);
+        }
+        match growth_exponent(&points) {
+            Some(exponent) => println!("{:<10}{:>10}{:>16.2}", op, "exponent", exponent),
+            None => println!("{:<10}{:>10}{:>16}", op, "exponent", "-"),
+        }
+        println!();
+    }
+
+    if options.save {
+        fs::write(&options.baseline, saved).expect("Could not write the baseline file");
+        println!("Saved baseline to {}", options.baseline);
+    } else if regressions > 0 {
+        println!("{} regression(s) against {}", regressions, options.baseline);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::bigint::BigInt;
+
+// Factorials computed so far in this process. A new request starts from the
+// largest memoized factorial below it instead of from scratch.
+static FACTORIALS: Mutex<BTreeMap<u64, BigInt>> = Mutex::new(BTreeMap::new());
+
+// Below this many factors a plain running product beats splitting further.
+const PRODUCT_LEAF: u64 = 16;
+
+// Product of lo..=hi (1 for an empty range). Splitting in halves keeps the
+// operands balanced so the large multiplications go through Karatsuba.
+fn product(lo: u64, hi: u64) -> BigInt {
+    if lo > hi {
+        return BigInt::one();
+    }
+    if hi - lo < PRODUCT_LEAF {
+        let mut result = BigInt::one();
+        for i in lo..=hi {
+            result *= i;
+        }
+        return result;
+    }
+    let mid = lo + (hi - lo) / 2;
+    product(lo, mid) * product(mid + 1, hi)
+}
+
+/// n!
+pub fn factorial(n: u64) -> BigInt {
+    let mut memo = FACTORIALS.lock().unwrap();
+    if let Some(value) = memo.get(&n) {
+        return value.clone();
+    }
+
+    let value = match memo.range(..n).next_back() {
+        Some((&m, base)) => base * &product(m + 1, n),
+        None => product(2, n),
+    };
+    memo.insert(n, value.clone());
+    value
+}
+
+/// Number of ways to choose `k` of `n` items; zero when `k > n`.
+pub fn binomial(n: u64, k: u64) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+    let k = k.min(n - k);
+    product(n - k + 1, n) / factorial(k)
+}
+
+/// The n-th Fibonacci number with F(0) = 0 and F(1) = 1, by fast doubling:
+/// F(2i) = F(i) * (2 F(i+1) - F(i)) and F(2i+1) = F(i)^2 + F(i+1)^2.
+pub fn fibonacci(n: u64) -> BigInt {
+    // (a, b) = (F(i), F(i+1)) for i = the bits of n seen so far.
+    let mut a = BigInt::zero();
+    let mut b = BigInt::one();
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        let c = &a * &(&b * 2u64 - &a);
+        let d = &a * &a + &b * &b;
+        if n >> bit & 1 == 1 {
+            b = &c + &d;
+            a = d;
+        } else {
+            a = c;
+            b = d;
+        }
+    }
+    a
+}
+
+/// The n-th Catalan number, binomial(2n, n) / (n + 1).
+pub fn catalan(n: u64) -> BigInt {
+    binomial(2 * n, n) / (n + 1)
+}
+
+/// Iterator over binomial(n, 0), binomial(n, 1), ..., binomial(n, n).
+pub fn pascal_row(n: u64) -> PascalRow {
+    PascalRow { n, k: 0, current: Some(BigInt::one()) }
+}
+
+pub struct PascalRow {
+    n: u64,
+    k: u64,
+    current: Option<BigInt>,
+}
+
+impl Iterator for PascalRow {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<BigInt> {
+        let value = self.current.take()?;
+        if self.k < self.n {
+            // binomial(n, k + 1) = binomial(n, k) * (n - k) / (k + 1), exactly.
+            self.current = Some(&value * (self.n - self.k) / (self.k + 1));
+            self.k += 1;
+        }
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = if self.current.is_some() { self.n - self.k + 1 } else { 0 };
+        let left = usize::try_from(left).ok();
+        (left.unwrap_or(usize::MAX), left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_factorial(n: u64) -> BigInt {
+        (1..=n).map(BigInt::from).product()
+    }
+
+    #[test]
+    fn memoized_factorials_match_a_fresh_product() {
+        // Each request extends the largest memoized one below it, in any order.
+        for n in [10, 20, 15, 0, 1, 300, 100, 301] {
+            assert_eq!(factorial(n), naive_factorial(n), "{}!", n);
+        }
+        assert_eq!(factorial(20), BigInt::from(2_432_902_008_176_640_000u64));
+    }
+
+    #[test]
+    fn binomials() {
+        assert_eq!(binomial(5, 6), BigInt::zero());
+        assert_eq!(binomial(0, 1), BigInt::zero());
+        assert_eq!(binomial(0, 0), BigInt::one());
+        assert_eq!(binomial(10, 3), BigInt::from(120));
+        assert_eq!(binomial(10, 7), BigInt::from(120));
+        assert_eq!(binomial(100, 50).to_string(), "100891344545564193334812497256");
+    }
+
+    #[test]
+    fn fibonacci_and_catalan_known_values() {
+        let fibonacci_values = [0u64, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+        for (n, &f) in fibonacci_values.iter().enumerate() {
+            assert_eq!(fibonacci(n as u64), BigInt::from(f), "F({})", n);
+        }
+        assert_eq!(fibonacci(93), BigInt::from(12_200_160_415_121_876_738u64));
+        assert_eq!(fibonacci(100).to_string(), "354224848179261915075");
+
+        let catalan_values = [1u64, 1, 2, 5, 14, 42, 132, 429, 1430, 4862];
+        for (n, &c) in catalan_values.iter().enumerate() {
+            assert_eq!(catalan(n as u64), BigInt::from(c), "C({})", n);
+        }
+        assert_eq!(catalan(30), BigInt::from(3_814_986_502_092_304u64));
+    }
+
+    #[test]
+    fn pascal_rows_sum_to_powers_of_two() {
+        for n in [0, 1, 2, 5, 64, 200] {
+            let row: Vec<BigInt> = pascal_row(n).collect();
+            assert_eq!(row.len() as u64, n + 1);
+            assert_eq!(row.iter().sum::<BigInt>(), BigInt::from(2).pow(n as u32), "row {}", n);
+            assert_eq!(row[n as usize / 3], binomial(n, n / 3), "row {}", n);
+        }
+        assert_eq!(pascal_row(4).size_hint(), (5, Some(5)));
+    }
+}
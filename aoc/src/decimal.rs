@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::bigint::{BigInt, ParseBigIntError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    // To the nearest, ties to the even neighbour ("banker's rounding").
+    HalfEven,
+    // Toward negative infinity.
+    Floor,
+    // Toward positive infinity.
+    Ceil,
+}
+
+// Exact decimal, mantissa / 10^scale. The scale is part of the value's
+// presentation: "1.50" and "1.5" compare equal but print differently.
+//
+// Add, Sub and Mul are exact, their scale being the larger scale and the sum
+// of scales respectively; `rescale` rounds a result to a chosen scale.
+// Division is rarely exact, so `div` takes the scale and rounding mode.
+#[derive(Debug, Clone)]
+pub struct BigDecimal {
+    pub mantissa: BigInt,
+    pub scale: u32,
+}
+
+impl BigDecimal {
+    pub fn new(mantissa: BigInt, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(BigInt::zero(), 0)
+    }
+
+    pub fn from_integer(n: BigInt) -> Self {
+        Self::new(n, 0)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa.is_negative()
+    }
+
+    /// The same value with `scale` fractional digits, rounded by `mode` when
+    /// digits are dropped.
+    pub fn rescale(&self, scale: u32, mode: RoundingMode) -> Self {
+        if scale >= self.scale {
+            let factor = pow10(scale - self.scale);
+            Self::new(&self.mantissa * &factor, scale)
+        } else {
+            let divisor = pow10(self.scale - scale);
+            Self::new(round_div(&self.mantissa, &divisor, mode), scale)
+        }
+    }
+
+    /// self / other with `scale` fractional digits, rounded by `mode`.
+    /// Panics if `other` is zero.
+    pub fn div(&self, other: &Self, scale: u32, mode: RoundingMode) -> Self {
+        // self / other * 10^scale == (m1 * 10^(scale + s2)) / (m2 * 10^s1)
+        let num = &self.mantissa * &pow10(scale + other.scale);
+        let den = &other.mantissa * &pow10(self.scale);
+        Self::new(round_div(&num, &den, mode), scale)
+    }
+
+    // Both mantissas at the larger of the two scales.
+    fn aligned(&self, other: &Self) -> (BigInt, BigInt, u32) {
+        let scale = self.scale.max(other.scale);
+        let a = &self.mantissa * &pow10(scale - self.scale);
+        let b = &other.mantissa * &pow10(scale - other.scale);
+        (a, b, scale)
+    }
+}
+
+fn pow10(exp: u32) -> BigInt {
+    BigInt::from(10).pow(exp)
+}
+
+// num / den rounded to an integer by `mode`.
+fn round_div(num: &BigInt, den: &BigInt, mode: RoundingMode) -> BigInt {
+    // With a positive divisor the floored remainder is in 0..den.
+    let (num, den) = if den.is_negative() { (-num, -den) } else { (num.clone(), den.clone()) };
+    let (quotient, remainder) = num.div_mod_floor(&den);
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    let round_up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => true,
+        RoundingMode::HalfEven => match (&remainder * 2u32).cmp(&den) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => !quotient.is_even(),
+        },
+    };
+    if round_up {
+        quotient + 1u32
+    } else {
+        quotient
+    }
+}
+
+impl From<BigInt> for BigDecimal {
+    fn from(n: BigInt) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl From<i64> for BigDecimal {
+    fn from(n: i64) -> Self {
+        Self::from_integer(BigInt::from(n))
+    }
+}
+
+// Operator overloading for references
+impl Add for &BigDecimal {
+    type Output = BigDecimal;
+    fn add(self, other: Self) -> BigDecimal {
+        let (a, b, scale) = self.aligned(other);
+        BigDecimal::new(a + b, scale)
+    }
+}
+
+impl Sub for &BigDecimal {
+    type Output = BigDecimal;
+    fn sub(self, other: Self) -> BigDecimal {
+        let (a, b, scale) = self.aligned(other);
+        BigDecimal::new(a - b, scale)
+    }
+}
+
+impl Mul for &BigDecimal {
+    type Output = BigDecimal;
+    fn mul(self, other: Self) -> BigDecimal {
+        BigDecimal::new(&self.mantissa * &other.mantissa, self.scale + other.scale)
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+    fn neg(self) -> BigDecimal {
+        BigDecimal::new(-&self.mantissa, self.scale)
+    }
+}
+
+// Operator overloading for owned values
+impl Add for BigDecimal {
+    type Output = BigDecimal;
+    fn add(self, other: BigDecimal) -> BigDecimal {
+        &self + &other
+    }
+}
+
+impl Sub for BigDecimal {
+    type Output = BigDecimal;
+    fn sub(self, other: BigDecimal) -> BigDecimal {
+        &self - &other
+    }
+}
+
+impl Mul for BigDecimal {
+    type Output = BigDecimal;
+    fn mul(self, other: BigDecimal) -> BigDecimal {
+        &self * &other
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+    fn neg(self) -> BigDecimal {
+        -&self
+    }
+}
+
+// Comparison by value, whatever the scales
+impl PartialEq for BigDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b, _) = self.aligned(other);
+        a.cmp(&b)
+    }
+}
+
+// Display with exactly `scale` fractional digits, "-123.4500"
+impl fmt::Display for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.mantissa.abs().to_string();
+        let scale = self.scale as usize;
+        let padded = format!("{:0>width$}", digits, width = scale + 1);
+        let (int, frac) = padded.split_at(padded.len() - scale);
+        let body = if scale == 0 { int.to_string() } else { format!("{}.{}", int, frac) };
+        f.pad_integral(!self.is_negative(), "", &body)
+    }
+}
+
+// Parses "-123.4500", "1." or ".5"; the scale is the number of digits after
+// the point, trailing zeros included.
+impl FromStr for BigDecimal {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (body, is_negative, start) = match s.strip_prefix('-') {
+            Some(rest) => (rest, true, 1),
+            None => (s, false, 0),
+        };
+
+        let mut digits = String::with_capacity(body.len());
+        let mut scale: Option<u32> = None;
+        for (i, c) in body.chars().enumerate() {
+            match c {
+                '0'..='9' => {
+                    digits.push(c);
+                    if let Some(scale) = scale.as_mut() {
+                        *scale += 1;
+                    }
+                }
+                '.' if scale.is_none() => scale = Some(0),
+                _ => return Err(ParseBigIntError::InvalidDigit { position: start + i, char: c }),
+            }
+        }
+        if digits.is_empty() {
+            return Err(ParseBigIntError::Empty);
+        }
+
+        let magnitude = BigInt::from_str(&digits)?;
+        let mantissa = if is_negative { -magnitude } else { magnitude };
+        Ok(BigDecimal::new(mantissa, scale.unwrap_or(0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(s: &str) -> BigDecimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn half_even_breaks_ties_to_even() {
+        let cases = [
+            ("0.5", "0"),
+            ("1.5", "2"),
+            ("2.5", "2"),
+            ("-0.5", "0"),
+            ("-1.5", "-2"),
+            ("-2.5", "-2"),
+            ("2.51", "3"),
+            ("-2.49", "-2"),
+        ];
+        for (input, expected) in cases {
+            let rounded = dec(input).rescale(0, RoundingMode::HalfEven);
+            assert_eq!(rounded.to_string(), expected, "{}", input);
+        }
+        // A tie whose quotient has many limbs.
+        let big = format!("{}5.5", "9".repeat(40));
+        let rounded = dec(&big).rescale(0, RoundingMode::HalfEven);
+        assert_eq!(rounded.to_string(), format!("{}6", "9".repeat(40)));
+    }
+
+    #[test]
+    fn floor_and_ceil_round_toward_infinities() {
+        let cases = [("1.25", "1.2", "1.3"), ("-1.25", "-1.3", "-1.2"), ("2.00", "2.0", "2.0")];
+        for (input, floor, ceil) in cases {
+            assert_eq!(dec(input).rescale(1, RoundingMode::Floor).to_string(), floor);
+            assert_eq!(dec(input).rescale(1, RoundingMode::Ceil).to_string(), ceil);
+        }
+        let third = BigDecimal::from(1).div(&BigDecimal::from(-3), 4, RoundingMode::HalfEven);
+        assert_eq!(third.to_string(), "-0.3333");
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for text in ["0", "-1", "123.4500", "-0.001", "0.000", "98765432109876543210.0123456789"] {
+            assert_eq!(dec(text).to_string(), text);
+        }
+        assert_eq!(dec("-.5").to_string(), "-0.5");
+        assert_eq!(dec("1.").to_string(), "1");
+        assert_eq!(dec("-0.0").to_string(), "0.0");
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(".".parse::<BigDecimal>().unwrap_err(), ParseBigIntError::Empty);
+        for text in ["", "-", "1.2.3", "1e5", " 1", "--1"] {
+            assert!(text.parse::<BigDecimal>().is_err(), "{:?}", text);
+        }
+    }
+}
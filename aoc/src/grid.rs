@@ -0,0 +1,75 @@
+use std::ops::{Add, AddAssign, Sub};
+
+// A position or offset on the plane, with y growing upwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Each coordinate reduced to -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Chebyshev distance: king moves on a chessboard.
+    pub fn chebyshev(self, other: Self) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    U,
+    D,
+    L,
+    R,
+}
+
+impl Dir {
+    /// "U", "D", "L" or "R".
+    pub fn from_letter(s: &str) -> Option<Dir> {
+        match s {
+            "U" => Some(Dir::U),
+            "D" => Some(Dir::D),
+            "L" => Some(Dir::L),
+            "R" => Some(Dir::R),
+            _ => None,
+        }
+    }
+
+    /// The unit step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::U => Point::new(0, 1),
+            Dir::D => Point::new(0, -1),
+            Dir::L => Point::new(-1, 0),
+            Dir::R => Point::new(1, 0),
+        }
+    }
+}
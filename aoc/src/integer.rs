@@ -0,0 +1,216 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::str::FromStr;
+
+use crate::bigint::BigInt;
+
+// Common interface of the primitive integers and BigInt, so a solver can be
+// written once and run with u64 for speed or with BigInt as an exact oracle.
+// `/` and `%` truncate toward zero, like the primitive operators.
+pub trait Integer:
+    Clone
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+
+    // `None` on overflow or division by zero. BigInt only fails on the latter.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    fn checked_rem(&self, other: &Self) -> Option<Self>;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        (self.clone() / other.clone(), self.clone() % other.clone())
+    }
+
+    fn abs(&self) -> Self {
+        if *self < Self::zero() {
+            Self::zero() - self.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Greatest common divisor, always non-negative. gcd(0, 0) == 0.
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.is_zero() {
+            let r = a % b.clone();
+            a = std::mem::replace(&mut b, r);
+        }
+        a
+    }
+
+    /// Least common multiple, always non-negative. lcm(0, x) == 0.
+    fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        (self.clone() / self.gcd(other) * other.clone()).abs()
+    }
+}
+
+// Marker for types with negative values, e.g. to hold Bezout coefficients.
+pub trait Signed: Integer {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *other)
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *other)
+            }
+
+            fn checked_div(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_div(*self, *other)
+            }
+
+            fn checked_rem(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_rem(*self, *other)
+            }
+        })*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+impl Integer for BigInt {
+    fn zero() -> Self {
+        BigInt::zero()
+    }
+
+    fn one() -> Self {
+        BigInt::one()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            None
+        } else {
+            Some(self % other)
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        BigInt::is_zero(self)
+    }
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        BigInt::div_rem(self, other)
+    }
+
+    fn abs(&self) -> Self {
+        BigInt::abs(self)
+    }
+}
+
+impl Signed for BigInt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (a, b, gcd, lcm), with signs where the type allows them.
+    const CASES: [(i64, i64, i64, i64); 8] = [
+        (12, 18, 6, 36),
+        (-12, 18, 6, 36),
+        (12, -18, 6, 36),
+        (-12, -18, 6, 36),
+        (0, 5, 5, 0),
+        (-5, 0, 5, 0),
+        (0, 0, 0, 0),
+        (17, 5, 1, 85),
+    ];
+
+    fn check_gcd_lcm<T: Integer>(a: i64, b: i64, gcd: i64, lcm: i64) {
+        let parse = |n: i64| n.to_string().parse::<T>().ok().unwrap();
+        let (x, y) = (parse(a), parse(b));
+        assert_eq!(x.gcd(&y), parse(gcd), "gcd({}, {})", a, b);
+        assert_eq!(y.gcd(&x), parse(gcd), "gcd({}, {})", b, a);
+        assert_eq!(x.lcm(&y), parse(lcm), "lcm({}, {})", a, b);
+    }
+
+    #[test]
+    fn gcd_and_lcm_on_primitives() {
+        for (a, b, gcd, lcm) in CASES {
+            check_gcd_lcm::<i64>(a, b, gcd, lcm);
+            if a >= 0 && b >= 0 {
+                check_gcd_lcm::<u32>(a, b, gcd, lcm);
+            }
+        }
+        assert_eq!(u64::MAX.gcd(&(u64::MAX - 1)), 1);
+        assert_eq!((1u64 << 63).lcm(&(1u64 << 40)), 1 << 63);
+    }
+
+    #[test]
+    fn gcd_and_lcm_on_bigint() {
+        for (a, b, gcd, lcm) in CASES {
+            check_gcd_lcm::<BigInt>(a, b, gcd, lcm);
+        }
+
+        // Coprime factors far past u128, times a shared one.
+        let p = BigInt::from(2).pow(127) - BigInt::one();
+        let q = BigInt::from(10).pow(40) + BigInt::one();
+        let shared = BigInt::from(1_000_000_007);
+        let (x, y) = (&p * &shared, -(&q * &shared));
+        assert_eq!(Integer::gcd(&p, &q), BigInt::one());
+        assert_eq!(Integer::gcd(&x, &y), shared);
+        assert_eq!(Integer::lcm(&x, &y), &(&p * &q) * &shared);
+    }
+}
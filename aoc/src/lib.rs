@@ -0,0 +1,16 @@
+// Code shared by every year's solutions.
+
+pub mod balanced;
+pub mod bigint;
+pub mod combinatorics;
+pub mod decimal;
+pub mod grid;
+pub mod integer;
+pub mod linalg;
+pub mod modint;
+pub mod numtheory;
+pub mod parsing;
+pub mod primes;
+pub mod rational;
+pub mod rng;
+pub mod solution;
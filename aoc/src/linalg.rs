@@ -0,0 +1,250 @@
+use crate::bigint::BigInt;
+use crate::rational::Rational;
+
+// Exact linear algebra over Rational. Matrices are row-major `Vec`s of rows.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinearSolution {
+    None,
+    Unique(Vec<Rational>),
+    // Every solution is `particular + t_1 * basis[0] + t_2 * basis[1] + ...`.
+    Infinite { particular: Vec<Rational>, basis: Vec<Vec<Rational>> },
+}
+
+fn check_rectangular<T>(a: &[Vec<T>]) -> usize {
+    let cols = a.first().map_or(0, |row| row.len());
+    assert!(a.iter().all(|row| row.len() == cols), "Rows have different lengths");
+    cols
+}
+
+// Gauss-Jordan elimination to reduced row echelon form, choosing pivots only
+// in the first `cols` columns. Returns the pivot column of each nonzero row.
+fn rref(m: &mut [Vec<Rational>], cols: usize) -> Vec<usize> {
+    let zero = Rational::zero();
+    let mut pivots = Vec::new();
+
+    for col in 0..cols {
+        let row = pivots.len();
+        let Some(found) = (row..m.len()).find(|&r| m[r][col] != zero) else {
+            continue;
+        };
+        m.swap(found, row);
+
+        let inv = m[row][col].recip();
+        for value in m[row].iter_mut() {
+            *value = &*value * &inv;
+        }
+        for r in 0..m.len() {
+            if r != row && m[r][col] != zero {
+                let factor = m[r][col].clone();
+                for c in 0..m[r].len() {
+                    let delta = &factor * &m[row][c];
+                    m[r][c] = &m[r][c] - &delta;
+                }
+            }
+        }
+
+        pivots.push(col);
+        if pivots.len() == m.len() {
+            break;
+        }
+    }
+
+    pivots
+}
+
+pub fn rank(a: &[Vec<Rational>]) -> usize {
+    let cols = check_rectangular(a);
+    let mut m = a.to_vec();
+    rref(&mut m, cols).len()
+}
+
+pub fn determinant(a: &[Vec<Rational>]) -> Rational {
+    let n = check_rectangular(a);
+    assert_eq!(a.len(), n, "Determinant of a non-square matrix");
+
+    // Plain forward elimination; the determinant is the product of the pivots.
+    let zero = Rational::zero();
+    let mut m = a.to_vec();
+    let mut det = Rational::one();
+    for k in 0..n {
+        let Some(found) = (k..n).find(|&r| m[r][k] != zero) else {
+            return zero;
+        };
+        if found != k {
+            m.swap(found, k);
+            det = -det;
+        }
+
+        det = &det * &m[k][k];
+        let (top, rest) = m.split_at_mut(k + 1);
+        let pivot = &top[k];
+        for row in rest {
+            let factor = &row[k] / &pivot[k];
+            for (x, p) in row[k..].iter_mut().zip(&pivot[k..]) {
+                *x = &*x - &(&factor * p);
+            }
+        }
+    }
+    det
+}
+
+/// Fraction-free (Bareiss) determinant of an integer matrix. Every division
+/// is exact, so the entries stay integers no larger than the minors.
+pub fn determinant_int(a: &[Vec<BigInt>]) -> BigInt {
+    let n = check_rectangular(a);
+    assert_eq!(a.len(), n, "Determinant of a non-square matrix");
+    if n == 0 {
+        return BigInt::one();
+    }
+
+    let zero = BigInt::zero();
+    let mut m = a.to_vec();
+    let mut negate = false;
+    let mut prev = BigInt::one();
+    for k in 0..n - 1 {
+        if m[k][k] == zero {
+            let Some(found) = (k + 1..n).find(|&r| m[r][k] != zero) else {
+                return zero;
+            };
+            m.swap(found, k);
+            negate = !negate;
+        }
+
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let cross = &(&m[i][j] * &m[k][k]) - &(&m[i][k] * &m[k][j]);
+                m[i][j] = &cross / &prev;
+            }
+        }
+        prev = m[k][k].clone();
+    }
+
+    let det = m[n - 1][n - 1].clone();
+    if negate {
+        &zero - &det
+    } else {
+        det
+    }
+}
+
+/// Solves `a * x == b`.
+pub fn solve(a: &[Vec<Rational>], b: &[Rational]) -> LinearSolution {
+    let cols = check_rectangular(a);
+    assert_eq!(a.len(), b.len(), "Right-hand side length differs from the row count");
+
+    let zero = Rational::zero();
+    let mut m: Vec<Vec<Rational>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, rhs)| row.iter().cloned().chain(std::iter::once(rhs.clone())).collect())
+        .collect();
+    let pivots = rref(&mut m, cols);
+
+    // A row reduced to 0 == c with c != 0 is a contradiction.
+    if m[pivots.len()..].iter().any(|row| row[cols] != zero) {
+        return LinearSolution::None;
+    }
+
+    let mut particular = vec![zero.clone(); cols];
+    for (row, &col) in pivots.iter().enumerate() {
+        particular[col] = m[row][cols].clone();
+    }
+    if pivots.len() == cols {
+        return LinearSolution::Unique(particular);
+    }
+
+    // One basis vector per free column: set it to 1 and solve the pivots.
+    let basis = (0..cols)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut v = vec![zero.clone(); cols];
+            v[free] = Rational::one();
+            for (row, &col) in pivots.iter().enumerate() {
+                v[col] = -&m[row][free];
+            }
+            v
+        })
+        .collect();
+
+    LinearSolution::Infinite { particular, basis }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn matrix(rows: &[&[i64]]) -> Vec<Vec<Rational>> {
+        rows.iter().map(|row| row.iter().map(|&x| Rational::from(x)).collect()).collect()
+    }
+
+    fn vector(values: &[i64]) -> Vec<Rational> {
+        values.iter().map(|&x| Rational::from(x)).collect()
+    }
+
+    fn apply(a: &[Vec<Rational>], x: &[Rational]) -> Vec<Rational> {
+        let dot = |row: &Vec<Rational>| {
+            row.iter().zip(x).fold(Rational::zero(), |acc, (p, q)| &acc + &(p * q))
+        };
+        a.iter().map(dot).collect()
+    }
+
+    #[test]
+    fn solves_unique_system() {
+        // x + 2y == 1 and 3x + 4y == 2 give x == 0, y == 1/2.
+        let a = matrix(&[&[1, 2], &[3, 4]]);
+        let half = Rational::new(BigInt::from(1u64), BigInt::from(2u64));
+        let expected = LinearSolution::Unique(vec![Rational::zero(), half]);
+        assert_eq!(solve(&a, &vector(&[1, 2])), expected);
+        assert_eq!(rank(&a), 2);
+    }
+
+    #[test]
+    fn detects_inconsistent_system() {
+        let a = matrix(&[&[1, 1], &[2, 2]]);
+        assert_eq!(solve(&a, &vector(&[1, 3])), LinearSolution::None);
+        assert_eq!(rank(&a), 1);
+    }
+
+    #[test]
+    fn parametrizes_underdetermined_system() {
+        let a = matrix(&[&[1, 1, 1, 1], &[1, 2, 3, 4], &[2, 3, 4, 5]]);
+        let b = vector(&[10, 30, 40]);
+        assert_eq!(rank(&a), 2);
+
+        let LinearSolution::Infinite { particular, basis } = solve(&a, &b) else {
+            panic!("Expected infinitely many solutions");
+        };
+        assert_eq!(basis.len(), 2);
+        assert_eq!(apply(&a, &particular), b);
+        for v in &basis {
+            assert_eq!(apply(&a, v), vector(&[0, 0, 0]));
+        }
+    }
+
+    #[test]
+    fn determinants_agree() {
+        assert_eq!(determinant(&matrix(&[&[0, 1], &[1, 0]])), Rational::from(-1));
+        assert_eq!(determinant(&matrix(&[&[1, 2], &[2, 4]])), Rational::zero());
+        assert_eq!(determinant_int(&[]), BigInt::one());
+
+        let mut rng = Rng::new(0xde7);
+        for n in 1..=6 {
+            for _ in 0..20 {
+                let ints: Vec<Vec<i64>> = (0..n)
+                    .map(|_| (0..n).map(|_| rng.below(7) as i64 - 3).collect())
+                    .collect();
+                let big: Vec<Vec<BigInt>> =
+                    ints.iter().map(|row| row.iter().map(|&x| BigInt::from(x)).collect()).collect();
+                let rational: Vec<Vec<Rational>> = ints.iter().map(|row| vector(row)).collect();
+                assert_eq!(
+                    determinant(&rational),
+                    Rational::from_integer(determinant_int(&big)),
+                    "{:?}",
+                    ints
+                );
+            }
+        }
+    }
+}
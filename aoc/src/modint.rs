@@ -0,0 +1,349 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+use crate::bigint::BigInt;
+use crate::numtheory::extended_gcd;
+
+/// A modulus with its precomputed Barrett constant. Any modulus in 1..=u64::MAX
+/// works, including even ones, so no BigInt is needed for the products.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Modulus {
+    m: u64,
+    // floor((2^128 - 1) / m)
+    r: u128,
+}
+
+impl Modulus {
+    pub const fn new(m: u64) -> Self {
+        assert!(m > 0, "Modulus must be positive");
+        Self { m, r: u128::MAX / m as u128 }
+    }
+
+    pub fn get(self) -> u64 {
+        self.m
+    }
+
+    pub fn of(self, n: u64) -> DynModInt {
+        DynModInt { value: n % self.m, modulus: self }
+    }
+
+    pub fn of_i64(self, n: i64) -> DynModInt {
+        DynModInt { value: reduce_i64(n, self.m), modulus: self }
+    }
+
+    pub fn of_bigint(self, n: &BigInt) -> DynModInt {
+        DynModInt { value: reduce_bigint(n, self.m), modulus: self }
+    }
+
+    // Barrett reduction: the estimated quotient is never too large and at most
+    // a few units too small, so a couple of subtractions finish the job.
+    fn reduce(self, x: u128) -> u64 {
+        let q = mul_high(x, self.r);
+        let mut rem = x - q * self.m as u128;
+        while rem >= self.m as u128 {
+            rem -= self.m as u128;
+        }
+        rem as u64
+    }
+
+    fn add(self, a: u64, b: u64) -> u64 {
+        let sum = a as u128 + b as u128;
+        if sum >= self.m as u128 {
+            (sum - self.m as u128) as u64
+        } else {
+            sum as u64
+        }
+    }
+
+    fn sub(self, a: u64, b: u64) -> u64 {
+        if a >= b {
+            a - b
+        } else {
+            self.m - (b - a)
+        }
+    }
+
+    fn mul(self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    fn neg(self, a: u64) -> u64 {
+        if a == 0 {
+            0
+        } else {
+            self.m - a
+        }
+    }
+
+    fn pow(self, mut base: u64, mut exp: u64) -> u64 {
+        let mut result = 1 % self.m;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn inverse(self, a: u64) -> Option<u64> {
+        let (g, x, _) = extended_gcd(a as i128, self.m as i128);
+        if g != 1 {
+            return None;
+        }
+        Some(x.rem_euclid(self.m as i128) as u64)
+    }
+
+    fn div(self, a: u64, b: u64) -> u64 {
+        match self.inverse(b) {
+            Some(inverse) => self.mul(a, inverse),
+            None => panic!("{} is not invertible modulo {}", b, self.m),
+        }
+    }
+}
+
+// High 128 bits of the 256-bit product a * b.
+fn mul_high(a: u128, b: u128) -> u128 {
+    let (a1, a0) = (a >> 64, a as u64 as u128);
+    let (b1, b0) = (b >> 64, b as u64 as u128);
+    let low = a0 * b0;
+    let mid1 = a1 * b0;
+    let mid2 = a0 * b1;
+    let carry = ((low >> 64) + (mid1 as u64 as u128) + (mid2 as u64 as u128)) >> 64;
+    a1 * b1 + (mid1 >> 64) + (mid2 >> 64) + carry
+}
+
+fn reduce_i64(n: i64, m: u64) -> u64 {
+    (n as i128).rem_euclid(m as i128) as u64
+}
+
+fn reduce_bigint(n: &BigInt, m: u64) -> u64 {
+    u64::try_from(&n.rem_euclid(&BigInt::from(m))).unwrap()
+}
+
+/// An integer modulo the compile-time constant `M`, always kept in 0..M.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    const MODULUS: Modulus = Modulus::new(M);
+
+    pub fn new(n: u64) -> Self {
+        Self { value: n % Self::MODULUS.m }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(0)
+    }
+
+    pub fn one() -> Self {
+        Self::new(1)
+    }
+
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn modulus(self) -> u64 {
+        M
+    }
+
+    fn shared(self, _other: Self) -> Modulus {
+        Self::MODULUS
+    }
+
+    fn with(self, value: u64) -> Self {
+        Self { value }
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(n: u64) -> Self {
+        Self::new(n)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(n: i64) -> Self {
+        Self { value: reduce_i64(n, Self::MODULUS.m) }
+    }
+}
+
+impl<const M: u64> From<&BigInt> for ModInt<M> {
+    fn from(n: &BigInt) -> Self {
+        Self { value: reduce_bigint(n, Self::MODULUS.m) }
+    }
+}
+
+impl<const M: u64> From<ModInt<M>> for BigInt {
+    fn from(n: ModInt<M>) -> Self {
+        BigInt::from(n.value)
+    }
+}
+
+/// An integer modulo a `Modulus` chosen at runtime, always kept in 0..m.
+/// Combining values with different moduli panics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynModInt {
+    value: u64,
+    modulus: Modulus,
+}
+
+impl DynModInt {
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn modulus(self) -> u64 {
+        self.modulus.m
+    }
+
+    fn shared(self, other: Self) -> Modulus {
+        assert_eq!(self.modulus.m, other.modulus.m, "Mismatched moduli");
+        self.modulus
+    }
+
+    fn with(self, value: u64) -> Self {
+        Self { value, modulus: self.modulus }
+    }
+}
+
+impl From<DynModInt> for BigInt {
+    fn from(n: DynModInt) -> Self {
+        BigInt::from(n.value)
+    }
+}
+
+macro_rules! impl_mod_ops {
+    ([$($g:tt)*] $t:ty) => {
+        impl<$($g)*> $t {
+            pub fn pow(self, exp: u64) -> Self {
+                self.with(self.shared(self).pow(self.value, exp))
+            }
+
+            /// The multiplicative inverse, if the value is coprime to the modulus.
+            pub fn inverse(self) -> Option<Self> {
+                self.shared(self).inverse(self.value).map(|value| self.with(value))
+            }
+        }
+
+        impl_mod_ops!(@binary [$($g)*] $t, Add add AddAssign add_assign);
+        impl_mod_ops!(@binary [$($g)*] $t, Sub sub SubAssign sub_assign);
+        impl_mod_ops!(@binary [$($g)*] $t, Mul mul MulAssign mul_assign);
+        impl_mod_ops!(@binary [$($g)*] $t, Div div DivAssign div_assign);
+
+        impl<$($g)*> Neg for $t {
+            type Output = Self;
+            fn neg(self) -> Self {
+                self.with(self.shared(self).neg(self.value))
+            }
+        }
+
+        impl<$($g)*> fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.value, f)
+            }
+        }
+    };
+    (@binary [$($g:tt)*] $t:ty, $tr:ident $m:ident $assign_tr:ident $assign:ident) => {
+        impl<$($g)*> $tr for $t {
+            type Output = Self;
+            fn $m(self, other: Self) -> Self {
+                self.with(self.shared(other).$m(self.value, other.value))
+            }
+        }
+
+        impl<$($g)*> $assign_tr for $t {
+            fn $assign(&mut self, other: Self) {
+                *self = self.$m(other);
+            }
+        }
+    };
+}
+
+impl_mod_ops!([const M: u64] ModInt<M>);
+impl_mod_ops!([] DynModInt);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    const PRIME: u64 = 998_244_353;
+
+    #[test]
+    fn reduce_matches_u128_remainder() {
+        let mut rng = Rng::new(0xba77);
+        let moduli = [1, 2, 3, PRIME, (1 << 32) + 1, u64::MAX / 3, u64::MAX - 1, u64::MAX];
+        for m in moduli {
+            let modulus = Modulus::new(m);
+            let mut values = vec![0, 1, m as u128, (m as u128) * (m as u128 - 1), u128::MAX];
+            for _ in 0..200 {
+                values.push(((rng.next_u64() as u128) << 64) | rng.next_u64() as u128);
+            }
+            for x in values {
+                assert_eq!(modulus.reduce(x) as u128, x % m as u128, "{} mod {}", x, m);
+            }
+        }
+    }
+
+    #[test]
+    fn mul_high_matches_wide_product() {
+        assert_eq!(mul_high(u128::MAX, u128::MAX), u128::MAX - 1);
+        assert_eq!(mul_high(1 << 64, 1 << 64), 1);
+        assert_eq!(mul_high(u128::MAX, 2), 1);
+    }
+
+    #[test]
+    fn pow_and_inverse_round_trip() {
+        // Fermat: a^(p-1) == 1 and a^(p-2) is the inverse.
+        for a in [1, 2, 3, 12345, PRIME - 1] {
+            let x = ModInt::<PRIME>::new(a);
+            assert_eq!(x.pow(PRIME - 1), ModInt::one());
+            assert_eq!(x.inverse(), Some(x.pow(PRIME - 2)));
+            assert_eq!(x * x.inverse().unwrap(), ModInt::one());
+            assert_eq!(ModInt::<PRIME>::one() / x * x, ModInt::one());
+        }
+        assert_eq!(ModInt::<PRIME>::zero().inverse(), None);
+
+        let big = Modulus::new(u64::MAX);
+        let x = big.of(u64::MAX - 2);
+        assert_eq!(x * x.inverse().unwrap(), big.of(1));
+        // u64::MAX is divisible by 3, so 3 has no inverse.
+        assert_eq!(big.of(3).inverse(), None);
+    }
+
+    #[test]
+    fn static_and_dynamic_moduli_agree() {
+        let modulus = Modulus::new(PRIME);
+        let mut rng = Rng::new(0xd1ff);
+        for _ in 0..1000 {
+            let (a, b) = (rng.next_u64(), rng.next_u64() as i64);
+            let (x, y) = (ModInt::<PRIME>::from(a), ModInt::<PRIME>::from(b));
+            let (dx, dy) = (modulus.of(a), modulus.of_i64(b));
+            assert_eq!((x + y).value(), (dx + dy).value());
+            assert_eq!((x - y).value(), (dx - dy).value());
+            assert_eq!((x * y).value(), (dx * dy).value());
+            assert_eq!((-x).value(), (-dx).value());
+            assert_eq!(BigInt::from(x * y), BigInt::from(dx * dy));
+        }
+    }
+
+    #[test]
+    fn modulus_one_maps_everything_to_zero() {
+        let one = Modulus::new(1);
+        assert_eq!(one.of(u64::MAX).value(), 0);
+        assert_eq!(one.of(5).pow(0).value(), 0);
+        assert_eq!(ModInt::<1>::new(7).pow(3).value(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not invertible")]
+    fn division_by_non_unit_panics() {
+        let _ = ModInt::<10>::new(3) / ModInt::new(4);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::bigint::BigInt;
+use crate::integer::{Integer, Signed};
+
+/// Greatest common divisor, always non-negative. gcd(0, 0) == 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(&b)
+}
+
+/// Least common multiple, always non-negative. lcm(0, x) == 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    a.lcm(&b)
+}
+
+/// Returns `(g, x, y)` with `a * x + b * y == g` and `g == gcd(a, b)`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::one(), T::zero());
+    let (mut y0, mut y1) = (T::zero(), T::one());
+
+    while !r1.is_zero() {
+        let q = r0.clone() / r1.clone();
+        let r = r0 - q.clone() * r1.clone();
+        let x = x0 - q.clone() * x1.clone();
+        let y = y0 - q * y1.clone();
+        r0 = std::mem::replace(&mut r1, r);
+        x0 = std::mem::replace(&mut x1, x);
+        y0 = std::mem::replace(&mut y1, y);
+    }
+
+    if r0 < T::zero() {
+        (T::zero() - r0, T::zero() - x0, T::zero() - y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Solves `x == residue (mod modulus)` for every pair, returning `(x, lcm)`
+/// with `x` in 0..lcm of all moduli. The moduli need not be coprime; `None`
+/// means the congruences contradict each other (or a modulus is zero).
+///
+/// Primitive types must be able to hold the square of the combined modulus,
+/// use `BigInt` when they can't. Unsigned types go through `crt_unsigned`.
+pub fn crt<T: Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut x = T::zero();
+    let mut m = T::one();
+
+    for (residue, modulus) in congruences {
+        let modulus = modulus.abs();
+        if modulus.is_zero() {
+            return None;
+        }
+
+        // x + m * t == residue (mod modulus)  <=>  m * t == diff (mod modulus)
+        let (g, p, _) = extended_gcd(m.clone(), modulus.clone());
+        let diff = residue.clone() - x.clone();
+        if !(diff.clone() % g.clone()).is_zero() {
+            return None;
+        }
+
+        let step = modulus / g.clone();
+        let t = rem_euclid(diff / g, step.clone()) * rem_euclid(p, step.clone());
+        let t = rem_euclid(t, step.clone());
+        x = x + m.clone() * t;
+        m = m * step;
+        x = rem_euclid(x, m.clone());
+    }
+
+    Some((x, m))
+}
+
+/// `crt` for unsigned types such as `u64`, which `extended_gcd` can't run on.
+/// The work is done in `BigInt`, so only the combined modulus has to fit in
+/// `T`; `None` also covers a combined modulus that doesn't.
+pub fn crt_unsigned<T>(congruences: &[(T, T)]) -> Option<(T, T)>
+where
+    T: Integer + Into<BigInt> + for<'a> TryFrom<&'a BigInt>,
+{
+    let congruences: Vec<(BigInt, BigInt)> =
+        congruences.iter().map(|(r, m)| (r.clone().into(), m.clone().into())).collect();
+    let (x, m) = crt(&congruences)?;
+    Some((T::try_from(&x).ok()?, T::try_from(&m).ok()?))
+}
+
+fn rem_euclid<T: Integer>(a: T, m: T) -> T {
+    let r = a % m.clone();
+    if r < T::zero() {
+        r + m
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extended_gcd_satisfies_bezout() {
+        for (a, b) in [(240i64, 46), (-240, 46), (17, 0), (0, -5), (0, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b), "{} {}", a, b);
+            assert_eq!(a * x + b * y, g, "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    fn crt_combines_moduli() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Not coprime but consistent: x == 1 (mod 4) and x == 3 (mod 6).
+        assert_eq!(crt(&[(1i64, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(-1i64, 5)]), Some((4, 5)));
+    }
+
+    #[test]
+    fn crt_rejects_contradictions() {
+        // x odd and x even.
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(1i64, 0)]), None);
+        assert_eq!(crt_unsigned(&[(1u64, 4), (2, 6)]), None);
+    }
+
+    #[test]
+    fn crt_unsigned_handles_large_moduli() {
+        let (p, q) = (4_294_967_291u64, 4_294_967_279u64);
+        let x = 12_345_678_901_234_567u64;
+        assert_eq!(crt_unsigned(&[(x % p, p), (x % q, q)]), Some((x, p * q)));
+        // The combined modulus overflows u64.
+        assert_eq!(crt_unsigned(&[(0u64, u64::MAX), (0, u64::MAX - 1)]), None);
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::solution::Error;
+
+/// Every run of ASCII digits in `line`, parsed in order. Signs are ignored,
+/// so "move 3 from -1" gives [3, 1]. Fails if a run doesn't fit in `T`.
+pub fn numbers<T: FromStr>(line: &str) -> Result<Vec<T>, Error>
+where
+    T::Err: Display,
+{
+    line.split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(|e| Error::new(format!("Invalid number {}: {}", s, e))))
+        .collect()
+}
+
+/// Parses each line of `input` with `f`, tagging a failure with its line number.
+pub fn lines<T>(input: &str, mut f: impl FnMut(&str) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+    input.lines().enumerate().map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1))).collect()
+}
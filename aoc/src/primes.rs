@@ -0,0 +1,319 @@
+use std::collections::BTreeMap;
+
+use crate::bigint::BigInt;
+use crate::numtheory::gcd;
+use crate::rng::Rng;
+
+// Fixed seed, so factorizations and probable-prime answers are reproducible.
+const SEED: u64 = 0x5eed;
+
+// Checking these witnesses is enough for every n < 3.18 * 10^23.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// Trial division removes these before Pollard rho gets involved.
+const SMALL_PRIMES: [u64; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Deterministic Miller-Rabin for the whole u64 range.
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for &p in &SMALL_PRIMES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    WITNESSES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Miller-Rabin with `rounds` random witnesses, so a composite passes with
+/// probability at most 4^-rounds. Exact for values that fit in a u64.
+pub fn is_probable_prime(n: &BigInt, rounds: u32) -> bool {
+    if let Ok(small) = u64::try_from(n) {
+        return is_prime_u64(small);
+    }
+    if n < &BigInt::zero() {
+        return false;
+    }
+    for &p in &SMALL_PRIMES {
+        if n.modulo(&BigInt::from(p)) == BigInt::zero() {
+            return false;
+        }
+    }
+
+    let one = BigInt::one();
+    let two = BigInt::from(2u32);
+    let n_minus_one = n - &one;
+    let mut d = n_minus_one.clone();
+    let mut s = 0;
+    while d.modulo(&two) == BigInt::zero() {
+        d = &d / &two;
+        s += 1;
+    }
+
+    let mut rng = Rng::new(SEED);
+    let range = n - &BigInt::from(3u32);
+    (0..rounds).all(|_| {
+        // A witness in 2..n - 1.
+        let a = &rng.below_big(&range) + &two;
+        let mut x = a.modpow(&d, n);
+        if x == one || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = (&x * &x).modulo(n);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Prime-power decomposition `[(p, e), ...]` of `n`, sorted by `p`. 1 has
+/// no factors.
+pub fn factor_u64(n: u64) -> Vec<(u64, u32)> {
+    assert!(n > 0, "Cannot factor zero");
+
+    let mut counts = BTreeMap::new();
+    let mut rng = Rng::new(SEED);
+    let n = strip_small_primes(n, &mut counts);
+    split_u64(n, &mut counts, &mut rng);
+    counts.into_iter().collect()
+}
+
+fn strip_small_primes(mut n: u64, counts: &mut BTreeMap<u64, u32>) -> u64 {
+    for &p in &SMALL_PRIMES {
+        while n.is_multiple_of(p) {
+            *counts.entry(p).or_insert(0) += 1;
+            n /= p;
+        }
+    }
+    n
+}
+
+fn split_u64(n: u64, counts: &mut BTreeMap<u64, u32>, rng: &mut Rng) {
+    if n == 1 {
+        return;
+    }
+    if is_prime_u64(n) {
+        *counts.entry(n).or_insert(0) += 1;
+        return;
+    }
+
+    let d = brent_u64(n, rng);
+    split_u64(d, counts, rng);
+    split_u64(n / d, counts, rng);
+}
+
+// Pollard rho with Brent's cycle detection and batched gcds. Returns a
+// nontrivial divisor of the odd composite `n`.
+fn brent_u64(n: u64, rng: &mut Rng) -> u64 {
+    const BATCH: u64 = 128;
+
+    loop {
+        let c = rng.below(n - 1) + 1;
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+
+        let mut y = rng.below(n);
+        let (mut x, mut ys) = (y, y);
+        let (mut r, mut q, mut g) = (1u64, 1u64, 1u64);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        // The batch overshot; step through it one gcd at a time.
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+
+        if g != n {
+            return g;
+        }
+    }
+}
+
+/// Prime-power decomposition of `|n|`, sorted by prime. Factors beyond the
+/// u64 range are found with Pollard rho, so huge semiprimes can take a while.
+pub fn factor(n: &BigInt) -> Vec<(BigInt, u32)> {
+    let n = if n < &BigInt::zero() { &BigInt::zero() - n } else { n.clone() };
+    if let Ok(small) = u64::try_from(&n) {
+        return factor_u64(small).into_iter().map(|(p, e)| (BigInt::from(p), e)).collect();
+    }
+
+    let mut counts = BTreeMap::new();
+    let mut rng = Rng::new(SEED);
+    let mut n = n;
+    for &p in &SMALL_PRIMES {
+        let p = BigInt::from(p);
+        while n.modulo(&p) == BigInt::zero() {
+            *counts.entry(p.clone()).or_insert(0) += 1;
+            n = &n / &p;
+        }
+    }
+    split(n, &mut counts, &mut rng);
+    counts.into_iter().collect()
+}
+
+fn split(n: BigInt, counts: &mut BTreeMap<BigInt, u32>, rng: &mut Rng) {
+    if let Ok(small) = u64::try_from(&n) {
+        for (p, e) in factor_u64(small) {
+            *counts.entry(BigInt::from(p)).or_insert(0) += e;
+        }
+        return;
+    }
+    if is_probable_prime(&n, 32) {
+        *counts.entry(n).or_insert(0) += 1;
+        return;
+    }
+
+    let d = brent(&n, rng);
+    let rest = &n / &d;
+    split(d, counts, rng);
+    split(rest, counts, rng);
+}
+
+// Same as `brent_u64`, over BigInt.
+fn brent(n: &BigInt, rng: &mut Rng) -> BigInt {
+    const BATCH: u64 = 128;
+    let one = BigInt::one();
+
+    loop {
+        let c = &rng.below_big(&(n - &one)) + &one;
+        let f = |x: &BigInt| (&(x * x) + &c).modulo(n);
+
+        let mut y = rng.below_big(n);
+        let (mut x, mut ys) = (y.clone(), y.clone());
+        let (mut r, mut q, mut g) = (1u64, one.clone(), one.clone());
+        while g == one {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g == one {
+                ys = y.clone();
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(&y);
+                    q = (&q * &(&x - &y)).rem_euclid(n);
+                }
+                g = gcd(q.clone(), n.clone());
+                k += BATCH;
+            }
+            r *= 2;
+        }
+
+        if &g == n {
+            loop {
+                ys = f(&ys);
+                g = gcd(&x - &ys, n.clone());
+                if g > one {
+                    break;
+                }
+            }
+        }
+
+        if &g != n {
+            return g;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_primes() {
+        let primes = [2, 3, 5, 37, 41, 97, 1_000_000_007, 4_294_967_291, 2_305_843_009_213_693_951];
+        for p in primes {
+            assert!(is_prime_u64(p), "{}", p);
+            assert!(is_probable_prime(&BigInt::from(p), 8), "{}", p);
+        }
+        assert!(is_prime_u64(18_446_744_073_709_551_557)); // Largest u64 prime.
+    }
+
+    #[test]
+    fn rejects_composites_and_pseudoprimes() {
+        let carmichael = [561, 1105, 1729, 2465, 6601, 8911];
+        // Strong pseudoprimes to bases 2..=7 and 2..=23 respectively.
+        let pseudoprimes = [3_215_031_751, 3_825_123_056_546_413_051];
+        for n in [0, 1, 4].into_iter().chain(carmichael).chain(pseudoprimes) {
+            assert!(!is_prime_u64(n), "{}", n);
+            assert!(!is_probable_prime(&BigInt::from(n), 8), "{}", n);
+        }
+        assert!(!is_prime_u64(u64::MAX));
+    }
+
+    #[test]
+    fn factors_u64() {
+        let fermat = vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)];
+        assert_eq!(factor_u64(u64::MAX), fermat);
+        assert_eq!(factor_u64(4_294_967_291 * 4_294_967_291), vec![(4_294_967_291, 2)]);
+        assert_eq!(factor_u64(1), vec![]);
+        assert_eq!(factor_u64(720), vec![(2, 4), (3, 2), (5, 1)]);
+    }
+
+    #[test]
+    fn factors_big_semiprime() {
+        let p = BigInt::from(2_305_843_009_213_693_951u64);
+        let q = BigInt::from(1_000_000_007u64);
+        let n = -(&(&p * &q) * &BigInt::from(12u64));
+        let expected = vec![(BigInt::from(2u64), 2), (BigInt::from(3u64), 1), (q, 1), (p, 1)];
+        assert_eq!(factor(&n), expected);
+    }
+}
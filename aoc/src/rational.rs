@@ -0,0 +1,374 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use crate::bigint::{BigInt, ParseBigIntError};
+use crate::numtheory::gcd;
+
+// Exact fraction. Always reduced, with the sign on the numerator and a
+// positive denominator, so equal values have equal fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rational {
+    num: BigInt,
+    den: BigInt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRationalError {
+    Int(ParseBigIntError),
+    ZeroDenominator,
+}
+
+impl fmt::Display for ParseRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseRationalError::Int(e) => write!(f, "{}", e),
+            ParseRationalError::ZeroDenominator => write!(f, "Zero denominator"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRationalError {}
+
+impl From<ParseBigIntError> for ParseRationalError {
+    fn from(e: ParseBigIntError) -> Self {
+        ParseRationalError::Int(e)
+    }
+}
+
+impl Rational {
+    pub fn new(num: BigInt, den: BigInt) -> Self {
+        if den == BigInt::zero() {
+            panic!("Zero denominator");
+        }
+
+        let g = gcd(num.clone(), den.clone());
+        let (mut num, mut den) = (&num / &g, &den / &g);
+        if den < BigInt::zero() {
+            num = &BigInt::zero() - &num;
+            den = &BigInt::zero() - &den;
+        }
+
+        Self { num, den }
+    }
+
+    pub fn from_integer(n: BigInt) -> Self {
+        Self { num: n, den: BigInt::one() }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_integer(BigInt::zero())
+    }
+
+    pub fn one() -> Self {
+        Self::from_integer(BigInt::one())
+    }
+
+    pub fn num(&self) -> &BigInt {
+        &self.num
+    }
+
+    pub fn den(&self) -> &BigInt {
+        &self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == BigInt::one()
+    }
+
+    pub fn recip(&self) -> Self {
+        Self::new(self.den.clone(), self.num.clone())
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.num < BigInt::zero() {
+            -self
+        } else {
+            self.clone()
+        }
+    }
+
+    pub fn floor(&self) -> BigInt {
+        self.num.div_floor(&self.den)
+    }
+
+    pub fn ceil(&self) -> BigInt {
+        let (quotient, remainder) = self.num.div_mod_floor(&self.den);
+        if remainder == BigInt::zero() {
+            quotient
+        } else {
+            &quotient + &BigInt::one()
+        }
+    }
+
+    /// Nearest integer, with halves rounded away from zero like `f64::round`.
+    pub fn round(&self) -> BigInt {
+        let half = Rational::new(BigInt::one(), BigInt::from(2));
+        if self.num < BigInt::zero() {
+            &BigInt::zero() - &(&self.abs() + &half).floor()
+        } else {
+            (self + &half).floor()
+        }
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(n: BigInt) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::from_integer(BigInt::from(n))
+    }
+}
+
+// Operator overloading for references
+impl<'a> Add for &'a Rational {
+    type Output = Rational;
+    fn add(self, other: &'a Rational) -> Rational {
+        let num = &(&self.num * &other.den) + &(&other.num * &self.den);
+        Rational::new(num, &self.den * &other.den)
+    }
+}
+
+impl<'a> Sub for &'a Rational {
+    type Output = Rational;
+    fn sub(self, other: &'a Rational) -> Rational {
+        let num = &(&self.num * &other.den) - &(&other.num * &self.den);
+        Rational::new(num, &self.den * &other.den)
+    }
+}
+
+impl<'a> Mul for &'a Rational {
+    type Output = Rational;
+    fn mul(self, other: &'a Rational) -> Rational {
+        Rational::new(&self.num * &other.num, &self.den * &other.den)
+    }
+}
+
+impl<'a> Div for &'a Rational {
+    type Output = Rational;
+    fn div(self, other: &'a Rational) -> Rational {
+        if other.num == BigInt::zero() {
+            panic!("Division by zero");
+        }
+        Rational::new(&self.num * &other.den, &self.den * &other.num)
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+    fn neg(self) -> Rational {
+        Rational { num: &BigInt::zero() - &self.num, den: self.den.clone() }
+    }
+}
+
+// Operator overloading for owned values
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, other: Rational) -> Rational {
+        &self + &other
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, other: Rational) -> Rational {
+        &self - &other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, other: Rational) -> Rational {
+        &self * &other
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    fn div(self, other: Rational) -> Rational {
+        &self / &other
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Rational {
+        -&self
+    }
+}
+
+// Comparison
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplying keeps the order.
+        (&self.num * &other.den).cmp(&(&other.num * &self.den))
+    }
+}
+
+// Display, "p/q", or just "p" for integers
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+// Parses "p/q", integers, and decimals such as "-1.25".
+impl FromStr for Rational {
+    type Err = ParseRationalError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((num, den)) = s.split_once('/') {
+            let num = BigInt::from_str(num)?;
+            let den_start = s.chars().count() - den.chars().count();
+            let den = BigInt::from_str(den).map_err(|e| shift_position(e, den_start))?;
+            if den == BigInt::zero() {
+                return Err(ParseRationalError::ZeroDenominator);
+            }
+            return Ok(Rational::new(num, den));
+        }
+
+        if let Some((int, frac)) = s.split_once('.') {
+            // Parse "-0.5" as -(0 + 5/10), the sign applies to both parts.
+            let (int, is_negative) = match int.strip_prefix('-') {
+                Some(rest) => (rest, true),
+                None => (int, false),
+            };
+            let int_start = is_negative as usize;
+            let frac_start = int_start + int.chars().count() + 1;
+
+            // Either part may be empty ("1." and ".5"), but not both.
+            let int = if int.is_empty() && !frac.is_empty() {
+                BigInt::zero()
+            } else {
+                parse_unsigned(int, int_start)?
+            };
+            let frac_value =
+                if frac.is_empty() { BigInt::zero() } else { parse_unsigned(frac, frac_start)? };
+
+            let frac_digits = frac.chars().filter(|&c| c != '_').count();
+            let scale = BigInt::from(10).pow(frac_digits as u32);
+            let magnitude = Rational::new(&(&int * &scale) + &frac_value, scale);
+            return Ok(if is_negative { -magnitude } else { magnitude });
+        }
+
+        Ok(Rational::from_integer(BigInt::from_str(s)?))
+    }
+}
+
+// A decimal part is digits only, its sign was already taken off the front.
+fn parse_unsigned(s: &str, offset: usize) -> Result<BigInt, ParseRationalError> {
+    if s.starts_with('-') {
+        let e = ParseBigIntError::InvalidDigit { position: 0, char: '-' };
+        return Err(shift_position(e, offset));
+    }
+    BigInt::from_str(s).map_err(|e| shift_position(e, offset))
+}
+
+fn shift_position(e: ParseBigIntError, offset: usize) -> ParseRationalError {
+    match e {
+        ParseBigIntError::InvalidDigit { position, char } => {
+            ParseRationalError::Int(ParseBigIntError::InvalidDigit {
+                position: position + offset,
+                char,
+            })
+        }
+        e => ParseRationalError::Int(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratio(s: &str) -> Rational {
+        s.parse().unwrap()
+    }
+
+    fn invalid(position: usize, char: char) -> Result<Rational, ParseRationalError> {
+        Err(ParseRationalError::Int(ParseBigIntError::InvalidDigit { position, char }))
+    }
+
+    #[test]
+    fn reduces_and_normalises_the_sign() {
+        let r = ratio("6/-4");
+        assert_eq!((r.num(), r.den()), (&BigInt::from(-3), &BigInt::from(2)));
+        assert_eq!(r.to_string(), "-3/2");
+        assert_eq!(ratio("-6/-4").to_string(), "3/2");
+        assert_eq!(ratio("0/-7"), Rational::zero());
+        assert_eq!(ratio("0/-7").den(), &BigInt::one());
+        assert_eq!(ratio("10/5").to_string(), "2");
+        assert_eq!(ratio("2/4"), ratio("-1/-2"));
+        assert_eq!(ratio("-3/2").recip().to_string(), "-2/3");
+        assert_eq!((&ratio("1/6") + &ratio("1/3")).to_string(), "1/2");
+        assert_eq!((&ratio("1/2") / &ratio("-1/4")).to_string(), "-2");
+    }
+
+    #[test]
+    fn parses_decimals() {
+        assert_eq!(ratio("-.5"), ratio("-1/2"));
+        assert_eq!(ratio(".5"), ratio("1/2"));
+        assert_eq!(ratio("1."), Rational::one());
+        assert_eq!(ratio("1.5_0"), ratio("3/2"));
+        assert_eq!(ratio("-1.25"), ratio("-5/4"));
+        assert_eq!(ratio("-0.0"), Rational::zero());
+        assert_eq!(ratio("0.000001"), ratio("1/1000000"));
+    }
+
+    #[test]
+    fn errors_point_into_the_whole_input() {
+        assert_eq!("x/2".parse::<Rational>(), invalid(0, 'x'));
+        assert_eq!("12/3x".parse::<Rational>(), invalid(4, 'x'));
+        assert_eq!("1.2x".parse::<Rational>(), invalid(3, 'x'));
+        assert_eq!("-1.-5".parse::<Rational>(), invalid(3, '-'));
+        assert_eq!("-1x.5".parse::<Rational>(), invalid(2, 'x'));
+        assert_eq!("1/-0".parse::<Rational>(), Err(ParseRationalError::ZeroDenominator));
+        let empty = Err(ParseRationalError::Int(ParseBigIntError::Empty));
+        assert_eq!(".".parse::<Rational>(), empty);
+        assert_eq!("1/".parse::<Rational>(), empty);
+    }
+
+    #[test]
+    fn rounds_negative_halves_away_from_zero() {
+        let cases = [
+            ("5/2", 2, 3, 3),
+            ("-5/2", -3, -2, -3),
+            ("-1/2", -1, 0, -1),
+            ("-7/3", -3, -2, -2),
+            ("-4", -4, -4, -4),
+            ("-3/2", -2, -1, -2),
+        ];
+        for (s, floor, ceil, round) in cases {
+            let r = ratio(s);
+            assert_eq!(r.floor(), BigInt::from(floor), "floor({})", s);
+            assert_eq!(r.ceil(), BigInt::from(ceil), "ceil({})", s);
+            assert_eq!(r.round(), BigInt::from(round), "round({})", s);
+        }
+    }
+
+    #[test]
+    fn orders_by_cross_multiplication() {
+        let sorted = ["-3/2", "-1/2", "-1/3", "0", "1/3", "333/999", "1/2", "2"];
+        for pair in sorted.windows(2) {
+            let (a, b) = (ratio(pair[0]), ratio(pair[1]));
+            let expected = if a == b { Ordering::Equal } else { Ordering::Less };
+            assert_eq!(a.cmp(&b), expected, "{} <=> {}", pair[0], pair[1]);
+            assert_eq!(b.cmp(&a), expected.reverse(), "{} <=> {}", pair[1], pair[0]);
+        }
+        assert_eq!(ratio("2/4").cmp(&ratio("-1/-2")), Ordering::Equal);
+        assert!(ratio("-1/1000000000000000000000") > ratio("-1/999999999999999999999"));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::bigint::BigInt;
+
+// Small seedable PRNG (SplitMix64), so randomized algorithms give the same
+// answers on every run and need no external crates.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in 0..n.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Empty range");
+        // Reject the top partial block of 2^64 so every residue is equally likely.
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    /// Uniform in 0..n, up to a bias below 2^-64.
+    pub fn below_big(&mut self, n: &BigInt) -> BigInt {
+        assert!(n > &BigInt::zero(), "Empty range");
+        let words = n.bit_len() / 64 + 2;
+        let mut x = BigInt::zero();
+        for _ in 0..words {
+            x = &x.shl(64) + &BigInt::from(self.next_u64());
+        }
+        x.rem_euclid(n)
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::time::{Duration, Instant};
+
+use crate::bigint::ParseBigIntError;
+
+// One day's puzzle, split into stages so a harness can run, time and test
+// each of them on its own. Parsing is the only stage allowed to fail: the
+// parts may assume `Parsed` is well formed.
+pub trait Solution {
+    type Parsed;
+    type Answer: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error>;
+    fn part1(parsed: &Self::Parsed) -> Self::Answer;
+    fn part2(parsed: &Self::Parsed) -> Self::Answer;
+}
+
+// Why an input was rejected. `line` is 1-based and unknown for errors that
+// are not tied to a single line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    line: Option<usize>,
+    message: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { line: None, message: message.into() }
+    }
+
+    /// The same error, reported against the 1-based `line`.
+    pub fn at_line(self, line: usize) -> Self {
+        Self { line: Some(line), ..self }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "Line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::new(format!("Invalid number: {}", e))
+    }
+}
+
+impl From<ParseBigIntError> for Error {
+    fn from(e: ParseBigIntError) -> Self {
+        Error::new(format!("Invalid number: {}", e))
+    }
+}
+
+// A part's answer, already formatted, and how long it took.
+#[derive(Debug, Clone)]
+pub struct Stage {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse: Duration,
+    pub part1: Stage,
+    pub part2: Stage,
+}
+
+/// Parses `input` once and runs both parts on it, timing every stage.
+pub fn run<S: Solution>(input: &str) -> Result<Report, Error> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    Ok(Report { parse, part1: time(|| S::part1(&parsed)), part2: time(|| S::part2(&parsed)) })
+}
+
+fn time<A: fmt::Display>(part: impl FnOnce() -> A) -> Stage {
+    let start = Instant::now();
+    let answer = part();
+    let elapsed = start.elapsed();
+    // Formatting is not part of the measured work.
+    Stage { answer: answer.to_string(), elapsed }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Parse ({:?})", self.parse)?;
+        for (name, stage) in [("Part 1", &self.part1), ("Part 2", &self.part2)] {
+            // Multi-line answers such as rendered letters start on their own line.
+            let sep = if stage.answer.contains('\n') { "\n" } else { " " };
+            writeln!(f, "{} ({:?}):{}{}", name, stage.elapsed, sep, stage.answer)?;
+        }
+        Ok(())
+    }
+}